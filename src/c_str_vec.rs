@@ -0,0 +1,169 @@
+use core::ffi::{c_char, CStr};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr;
+use flat::BytesVec;
+use vec::ChillVec as Vec;
+
+/// A list of nul-terminated strings stored in one allocation, for building the `argv`- or
+/// `envp`-style string tables that C APIs take.
+///
+/// ```
+/// # use panicless::CStrVec;
+/// let mut args = CStrVec::new();
+/// assert_eq!(args.push(b"ls"), Some(()));
+/// assert_eq!(args.push(b"-l"), Some(()));
+/// assert_eq!(args.push(b"bad\0arg"), None);
+/// assert_eq!(args.len(), 2);
+///
+/// let argv = args.pointers();
+/// // argv.as_ptr() can now be passed to execv and friends
+/// assert_eq!(argv.len(), 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct CStrVec {
+    // Every entry is a string followed by its only nul
+    strings: BytesVec,
+}
+
+/// A null-terminated array of pointers to the strings in a `CStrVec`, valid for as long as the
+/// `CStrVec` is borrowed.
+pub struct CStrArray<'a> {
+    pointers: Vec<*const c_char>,
+    strings: PhantomData<&'a CStrVec>,
+}
+
+impl<'a> CStrArray<'a> {
+    /// The number of strings, not counting the null pointer at the end
+    pub fn len(&self) -> usize {
+        self.pointers.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A pointer to the first of `len() + 1` pointers, the last of which is null.
+    pub fn as_ptr(&self) -> *const *const c_char {
+        self.pointers.as_ptr()
+    }
+}
+
+impl fmt::Debug for CStrVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl CStrVec {
+    pub fn new() -> Self {
+        CStrVec {
+            strings: BytesVec::new(),
+        }
+    }
+
+    /// The number of bytes allocated on the heap, including unused capacity.
+    pub fn heap_size(&self) -> usize {
+        self.strings.heap_size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Appends `bytes` followed by a nul, or returns `None` and leaves the CStrVec alone if there
+    /// is a nul in `bytes`.
+    pub fn push(&mut self, bytes: &[u8]) -> Option<()> {
+        if bytes.contains(&0) {
+            return None;
+        }
+        // Add the nul to the entry push just made
+        self.strings.push(bytes);
+        self.strings.data.push(0);
+        if let Some(end) = self.strings.indices.last_mut() {
+            *end += 1;
+        }
+        Some(())
+    }
+
+    pub fn push_c_str(&mut self, s: &CStr) {
+        self.strings.push(s.to_bytes_with_nul());
+    }
+
+    pub fn get(&self, index: usize) -> Option<&CStr> {
+        self.strings.get(index).map(to_c_str)
+    }
+
+    /// Removes all the strings, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.strings.clear();
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &CStr> + ExactSizeIterator + '_ {
+        self.strings.iter().map(to_c_str)
+    }
+
+    /// Builds the null-terminated array of pointers to each string that C expects for `argv` and
+    /// similar parameters. This allocates the array; the strings themselves are not copied.
+    pub fn pointers(&self) -> CStrArray<'_> {
+        let mut pointers = Vec::with_capacity(self.len().saturating_add(1));
+        pointers.extend(self.iter().map(CStr::as_ptr));
+        pointers.push(ptr::null());
+        CStrArray {
+            pointers,
+            strings: PhantomData,
+        }
+    }
+}
+
+fn to_c_str(bytes: &[u8]) -> &CStr {
+    // Every entry ends in its only nul, see push
+    unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(bytes: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    #[test]
+    fn push_get() {
+        let mut strings = CStrVec::new();
+        assert_eq!(strings.push(b"hello"), Some(()));
+        assert_eq!(strings.push(b""), Some(()));
+        assert_eq!(strings.push(b"nul\0"), None);
+        strings.push_c_str(c(b"world\0"));
+        assert_eq!(strings.len(), 3);
+        assert_eq!(strings.get(0), Some(c(b"hello\0")));
+        assert_eq!(strings.get(1), Some(c(b"\0")));
+        assert_eq!(strings.get(2), Some(c(b"world\0")));
+        assert_eq!(strings.get(3), None);
+        assert!(strings
+            .iter()
+            .rev()
+            .eq([c(b"world\0"), c(b"\0"), c(b"hello\0")]));
+        strings.clear();
+        assert!(strings.is_empty());
+        assert!(strings.pointers().is_empty());
+    }
+
+    #[test]
+    fn pointers() {
+        let mut strings = CStrVec::new();
+        strings.push(b"a").unwrap();
+        strings.push(b"bc").unwrap();
+        let array = strings.pointers();
+        assert_eq!(array.len(), 2);
+        let pointers = unsafe { core::slice::from_raw_parts(array.as_ptr(), 3) };
+        assert_eq!(unsafe { CStr::from_ptr(pointers[0]) }, c(b"a\0"));
+        assert_eq!(unsafe { CStr::from_ptr(pointers[1]) }, c(b"bc\0"));
+        assert!(pointers[2].is_null());
+    }
+}
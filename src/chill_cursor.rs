@@ -0,0 +1,162 @@
+use core::convert::TryFrom;
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+/// Reads from and seeks around an in-memory buffer, usually a `ChillVec<u8>` or a slice of one.
+/// This is `std::io::Cursor` with all the position arithmetic checked: seeking before the start
+/// or past `u64::MAX` is an error, and reading from beyond the end returns no bytes.
+///
+/// ```
+/// # use panicless::{ChillCursor, ChillVec};
+/// use std::io::{Read, Seek, SeekFrom, Write};
+///
+/// let mut vec = ChillVec::new();
+/// vec.write_all(b"hello world").unwrap();
+///
+/// let mut cursor = ChillCursor::new(vec);
+/// cursor.seek(SeekFrom::Start(6)).unwrap();
+/// let mut word = String::new();
+/// cursor.read_to_string(&mut word).unwrap();
+/// assert_eq!(word, "world");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ChillCursor<T> {
+    inner: T,
+    pos: u64,
+}
+
+impl<T> ChillCursor<T> {
+    /// Wraps `inner` with the position at the start
+    pub fn new(inner: T) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+}
+
+impl<T: AsRef<[u8]>> ChillCursor<T> {
+    /// The bytes from the position to the end, which is empty if the position is past the end
+    pub fn remaining_slice(&self) -> &[u8] {
+        let data = self.inner.as_ref();
+        // A position that doesn't fit in a usize is certainly past the end
+        let start = usize::try_from(self.pos).unwrap_or(usize::MAX);
+        data.get(start..).unwrap_or(&[])
+    }
+}
+
+impl<T: AsRef<[u8]>> Read for ChillCursor<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = {
+            let remaining = self.remaining_slice();
+            let n = remaining.len().min(buf.len());
+            buf[..n].copy_from_slice(&remaining[..n]);
+            n
+        };
+        // n is only non-zero if the position is in bounds, so this can't overflow
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: AsRef<[u8]>> BufRead for ChillCursor<T> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_add(amt as u64);
+    }
+}
+
+impl<T: AsRef<[u8]>> Seek for ChillCursor<T> {
+    fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            SeekFrom::Start(pos) => {
+                self.pos = pos;
+                return Ok(pos);
+            }
+            SeekFrom::End(offset) => (self.inner.as_ref().len() as u64, offset),
+            SeekFrom::Current(offset) => (self.pos, offset),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(pos) => {
+                self.pos = pos;
+                Ok(pos)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::v1::*;
+    use vec::ChillVec;
+
+    #[test]
+    fn read() {
+        let mut cursor = ChillCursor::new(ChillVec::from(*b"abcdef"));
+        let mut buf = [0; 4];
+        assert_eq!(cursor.read(&mut buf).unwrap(), 4);
+        assert_eq!(buf, *b"abcd");
+        assert_eq!(cursor.read(&mut buf).unwrap(), 2);
+        assert_eq!(buf[..2], *b"ef");
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+        assert_eq!(cursor.position(), 6);
+
+        cursor.set_position(u64::MAX);
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+        assert!(cursor.remaining_slice().is_empty());
+    }
+
+    #[test]
+    fn seek() {
+        let data = ChillVec::from(*b"0123456789");
+        let mut cursor = ChillCursor::new(&data[..]);
+
+        assert_eq!(cursor.seek(SeekFrom::End(-3)).unwrap(), 7);
+        assert_eq!(cursor.remaining_slice(), b"789");
+        assert_eq!(cursor.seek(SeekFrom::Current(-7)).unwrap(), 0);
+        assert_eq!(cursor.seek(SeekFrom::Current(20)).unwrap(), 20);
+        assert!(cursor.remaining_slice().is_empty());
+
+        assert!(cursor.seek(SeekFrom::Current(-21)).is_err());
+        assert!(cursor.seek(SeekFrom::End(-11)).is_err());
+        cursor.set_position(u64::MAX);
+        assert!(cursor.seek(SeekFrom::Current(1)).is_err());
+        // Failed seeks leave the position alone
+        assert_eq!(cursor.position(), u64::MAX);
+    }
+
+    #[test]
+    fn buf_read() {
+        let mut cursor = ChillCursor::new(ChillVec::from(*b"one\ntwo\n"));
+        let mut line = String::new();
+        cursor.read_line(&mut line).unwrap();
+        assert_eq!(line, "one\n");
+        cursor.consume(usize::MAX);
+        assert!(cursor.fill_buf().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+/// Decides where a `CursorVec`'s cursor goes when it is moved past either end.
+pub trait CursorMode {
+    /// Returns the index `next` moves the cursor to from `index`, in a CursorVec of `len`
+    /// elements. `len` is never 0 and `index` is always less than it. A result that is out of
+    /// bounds leaves the cursor where it was.
+    fn next(index: usize, len: usize) -> usize;
+
+    /// Like `next`, but for `prev`.
+    fn prev(index: usize, len: usize) -> usize;
+}
+
+/// Moving past the last element goes to the first, and before the first goes to the last. This
+/// is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Wrap;
+
+impl CursorMode for Wrap {
+    #[inline]
+    fn next(index: usize, len: usize) -> usize {
+        if index + 1 == len {
+            0
+        } else {
+            index + 1
+        }
+    }
+
+    #[inline]
+    fn prev(index: usize, len: usize) -> usize {
+        if index == 0 {
+            len - 1
+        } else {
+            index - 1
+        }
+    }
+}
+
+/// The cursor stops at the first and last elements, like the steps of a wizard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Saturate;
+
+impl CursorMode for Saturate {
+    #[inline]
+    fn next(index: usize, len: usize) -> usize {
+        if index + 1 == len {
+            index
+        } else {
+            index + 1
+        }
+    }
+
+    #[inline]
+    fn prev(index: usize, _len: usize) -> usize {
+        index.saturating_sub(1)
+    }
+}
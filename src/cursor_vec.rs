@@ -1,19 +1,80 @@
 /// A continer backed by a Vec with a cursor that always points to a valid element,
 /// and therefore it is always possible to get the current element.
 /// The backing container must never be empty.
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use cursor_mode::{CursorMode, Wrap};
 use vec::ChillVec as Vec;
 
-pub struct CursorVec<T> {
+/// `M` decides what happens when the cursor is moved past either end, see `CursorMode`.
+#[derive(Debug)]
+pub struct CursorVec<T, M = Wrap> {
     index: usize,
     vec: Vec<T>,
+    mode: PhantomData<M>,
 }
 
 impl<T> CursorVec<T> {
     /// Construct a CursorVec from a single element
     pub fn new(first: T) -> CursorVec<T> {
+        Self::with_mode(first)
+    }
+
+    /// Takes over `vec` with the cursor on its first element, or returns `None` if it's empty.
+    pub fn from_vec(vec: Vec<T>) -> Option<CursorVec<T>> {
+        if vec.is_empty() {
+            None
+        } else {
+            Some(CursorVec {
+                index: 0,
+                vec,
+                mode: PhantomData,
+            })
+        }
+    }
+}
+
+impl<T: Clone> CursorVec<T> {
+    /// Clones `items` into a new CursorVec with the cursor on the first element, or returns
+    /// `None` if there are no items.
+    pub fn from_slice(items: &[T]) -> Option<CursorVec<T>> {
+        if items.is_empty() {
+            return None;
+        }
+        let mut vec = Vec::with_capacity(items.len());
+        vec.extend_from_slice(items);
+        Self::from_vec(vec)
+    }
+}
+
+impl<T, M: CursorMode> CursorVec<T, M> {
+    /// Like `new`, but for a CursorVec with a cursor mode other than the default.
+    ///
+    /// ```
+    /// # use panicless::{CursorVec, Saturate};
+    /// let mut steps = CursorVec::<_, Saturate>::with_mode("start");
+    /// steps.push("finish");
+    /// assert!(steps.next());
+    /// assert!(!steps.next());
+    /// assert_eq!(*steps.get(), "finish");
+    /// ```
+    pub fn with_mode(first: T) -> Self {
         let mut vec = Vec::new();
         vec.push(first);
-        Self { index: 0, vec }
+        CursorVec {
+            index: 0,
+            vec,
+            mode: PhantomData,
+        }
+    }
+
+    /// Switches to a different cursor mode, keeping the elements and the cursor.
+    pub fn into_mode<N>(self) -> CursorVec<T, N> {
+        CursorVec {
+            index: self.index,
+            vec: self.vec,
+            mode: PhantomData,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -28,19 +89,59 @@ impl<T> CursorVec<T> {
         unsafe { self.vec.get_unchecked_mut(self.index) }
     }
 
-    pub fn next(&mut self) {
-        self.index += 1;
-        if self.index == self.vec.len() {
-            self.index = 0;
-        }
+    /// Moves the cursor to the next element, returning whether it moved. Past the last element
+    /// it wraps around or stays put, depending on the mode.
+    // Not an iterator: the cursor never runs out of elements
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> bool {
+        let next = self.next_index();
+        let moved = next != self.index;
+        self.index = next;
+        moved
+    }
+
+    /// Moves the cursor to the previous element, returning whether it moved. Before the first
+    /// element it wraps around or stays put, depending on the mode.
+    pub fn prev(&mut self) -> bool {
+        let prev = self.prev_index();
+        let moved = prev != self.index;
+        self.index = prev;
+        moved
+    }
+
+    /// The element `delta` places after the cursor, or before it if `delta` is negative,
+    /// wrapping around either end whatever the mode.
+    pub fn get_offset(&self, delta: isize) -> &T {
+        // A ChillVec never holds more than isize::MAX elements
+        let len = self.vec.len();
+        let forward = delta.rem_euclid(len as isize) as usize;
+        unsafe { self.vec.get_unchecked((self.index + forward) % len) }
     }
 
-    pub fn prev(&mut self) {
-        if self.index == 0 {
-            self.index = self.vec.len() - 1;
+    /// The element `next` would move the cursor to, without moving it
+    pub fn peek_next(&self) -> &T {
+        unsafe { self.vec.get_unchecked(self.next_index()) }
+    }
+
+    /// The element `prev` would move the cursor to, without moving it
+    pub fn peek_prev(&self) -> &T {
+        unsafe { self.vec.get_unchecked(self.prev_index()) }
+    }
+
+    fn next_index(&self) -> usize {
+        self.checked_index(M::next(self.index, self.vec.len()))
+    }
+
+    fn prev_index(&self) -> usize {
+        self.checked_index(M::prev(self.index, self.vec.len()))
+    }
+
+    /// The cursor must stay in bounds whatever the mode says
+    fn checked_index(&self, index: usize) -> usize {
+        if index < self.vec.len() {
+            index
         } else {
-            // Doesn't matter what we use here, because it's always > 0
-            self.index += 1;
+            self.index
         }
     }
 
@@ -52,6 +153,77 @@ impl<T> CursorVec<T> {
         self.vec.push(item)
     }
 
+    /// Removes and returns the current element, moving the cursor to the element after it. If it
+    /// was the last element, that is the first element or the new last one, depending on the
+    /// mode. Returns `None`, leaving the CursorVec unchanged, if this is the only element, since a
+    /// CursorVec can't be empty.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.vec.len() <= 1 {
+            return None;
+        }
+        let removed = self.vec.drain(self.index..self.index + 1)?.next();
+        if self.index == self.vec.len() {
+            // What used to be the next element depends on whether the cursor wraps
+            self.index -= 1;
+            self.index = self.next_index();
+        }
+        removed
+    }
+
+    /// Keeps only the elements for which `f` returns `true`. If that would leave no elements,
+    /// the CursorVec ends up holding just `fallback` instead, since it can't be empty.
+    ///
+    /// If the current element is kept the cursor stays on it, otherwise it moves to the next
+    /// element that was kept, as in `remove_current`.
+    pub fn retain_or<F>(&mut self, mut f: F, fallback: T)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        // Deciding first means a panic in f can't leave the CursorVec half filtered, or empty
+        let keep: Vec<bool> = self.vec.iter().map(&mut f).collect();
+        let kept = keep.iter().filter(|&&k| k).count();
+        let kept_before = keep.iter().take(self.index).filter(|&&k| k).count();
+        let current_kept = keep.get(self.index).copied().unwrap_or(false);
+
+        let mut decisions = keep.iter();
+        self.vec
+            .retain(|_| decisions.next().copied().unwrap_or(true));
+
+        if kept == 0 {
+            self.vec.push(fallback);
+            self.index = 0;
+        } else if current_kept || kept_before < kept {
+            // Either the current element or the first kept one after it
+            self.index = kept_before;
+        } else {
+            self.index = kept - 1;
+            self.index = self.next_index();
+        }
+    }
+
+    /// Swaps the current element with the one at `index` and moves the cursor along with it, so
+    /// it stays on the same element. Returns `false` and changes nothing if `index` is out of
+    /// bounds.
+    ///
+    /// ```
+    /// # use panicless::CursorVec;
+    /// let mut list = CursorVec::new("a");
+    /// list.push("b");
+    /// list.push("c");
+    /// // Move "a" down one place
+    /// assert!(list.swap_current_with(1));
+    /// assert_eq!(*list.get(), "a");
+    /// assert!(list.iter().eq(&["b", "a", "c"]));
+    /// ```
+    pub fn swap_current_with(&mut self, index: usize) -> bool {
+        if self.vec.swap(self.index, index) {
+            self.index = index;
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.vec.iter()
     }
@@ -60,19 +232,321 @@ impl<T> CursorVec<T> {
         self.vec.iter_mut()
     }
 
+    /// Iterates over every element once, starting at the cursor and wrapping around to the
+    /// elements before it, whatever the mode.
+    pub fn iter_from_cursor(&self) -> impl Iterator<Item = &T> {
+        // The cursor is always in bounds, so this never falls back to the default
+        let (before, after) = self.vec.split_at_checked(self.index).unwrap_or_default();
+        after.iter().chain(before)
+    }
+
+    /// Moves the cursor to the next element for which `f` returns `true`, searching forwards and
+    /// wrapping around whatever the mode, so the current element is checked last. Calling this
+    /// repeatedly visits each match in turn. Returns `false` and leaves the cursor where it was
+    /// if nothing matches.
+    pub fn find_and_seek<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> bool {
+        let len = self.vec.len();
+        let found = (1..=len)
+            .map(|step| (self.index + step) % len)
+            .find(|&i| self.vec.get(i).is_some_and(&mut f));
+        match found {
+            Some(index) => {
+                self.index = index;
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn tell(&self) -> usize {
         self.index
     }
 
+    /// Moves the cursor to `index`, or returns `false` and leaves it where it was if `index` is
+    /// out of bounds.
+    pub fn seek(&mut self, index: usize) -> bool {
+        if index < self.vec.len() {
+            self.index = index;
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.vec.len()
     }
 
+    /// The number of bytes allocated on the heap for the elements, including unused capacity.
+    pub fn heap_size(&self) -> usize {
+        self.vec.heap_size()
+    }
+
+    /// Sorts the elements by the key `f` returns, the same as `sort_by_key_keep_cursor`.
     pub fn sort_by_key<K, F>(&mut self, f: F)
     where
         F: FnMut(&T) -> K,
         K: Ord,
     {
+        self.sort_by_key_keep_cursor(f);
+    }
+
+    /// Sorts the elements by the key `f` returns, then moves the cursor to wherever the current
+    /// element ended up. The sort is stable.
+    pub fn sort_by_key_keep_cursor<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        // Because the sort is stable, the current element lands after every element with a
+        // smaller key and every element before it with an equal key
+        let current = f(self.get());
+        let index = self.index;
+        self.index = self
+            .vec
+            .iter()
+            .enumerate()
+            .filter(|&(i, item)| match f(item).cmp(&current) {
+                Ordering::Less => true,
+                Ordering::Equal => i < index,
+                Ordering::Greater => false,
+            })
+            .count();
         self.vec.sort_by_key(f);
     }
 }
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use super::{CursorVec, PhantomData, Vec};
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    impl<'a, T: Arbitrary<'a>, M> Arbitrary<'a> for CursorVec<T, M> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            // The first element isn't optional, a CursorVec is never empty
+            let mut vec = Vec::new();
+            vec.push(T::arbitrary(u)?);
+            for item in u.arbitrary_iter()? {
+                vec.push(item?);
+            }
+            let index = u.choose_index(vec.len())?;
+            Ok(CursorVec {
+                index,
+                vec,
+                mode: PhantomData,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cursor_mode::Saturate;
+    use std::string::String;
+
+    fn abc() -> CursorVec<char> {
+        let mut cursor = CursorVec::new('a');
+        cursor.push('b');
+        cursor.push('c');
+        cursor
+    }
+
+    #[test]
+    fn remove_current() {
+        let mut cursor = abc();
+        cursor.next();
+        assert_eq!(cursor.remove_current(), Some('b'));
+        assert_eq!(*cursor.get(), 'c');
+        assert_eq!(cursor.remove_current(), Some('c'));
+        assert_eq!(*cursor.get(), 'a');
+        assert_eq!(cursor.tell(), 0);
+        assert_eq!(cursor.remove_current(), None);
+        assert_eq!(cursor.len(), 1);
+        assert_eq!(*cursor.get(), 'a');
+    }
+
+    #[test]
+    fn seek() {
+        let mut cursor = abc();
+        assert!(cursor.seek(2));
+        assert_eq!(*cursor.get(), 'c');
+        assert!(!cursor.seek(3));
+        assert_eq!(cursor.tell(), 2);
+        assert!(cursor.seek(0));
+        assert_eq!(*cursor.get(), 'a');
+    }
+
+    #[test]
+    fn peek() {
+        let mut cursor = abc();
+        assert_eq!((*cursor.peek_prev(), *cursor.peek_next()), ('c', 'b'));
+        cursor.next();
+        assert_eq!((*cursor.peek_prev(), *cursor.peek_next()), ('a', 'c'));
+        cursor.prev();
+        assert_eq!(*cursor.get(), 'a');
+        cursor.prev();
+        assert_eq!(*cursor.get(), 'c');
+        assert_eq!((*cursor.peek_prev(), *cursor.peek_next()), ('b', 'a'));
+
+        let single = CursorVec::new(1);
+        assert_eq!((*single.peek_prev(), *single.peek_next()), (1, 1));
+    }
+
+    #[test]
+    fn saturate() {
+        let mut cursor = abc().into_mode::<Saturate>();
+        assert!(!cursor.prev());
+        assert_eq!(*cursor.peek_prev(), 'a');
+        assert!(cursor.next());
+        assert!(cursor.next());
+        assert!(!cursor.next());
+        assert_eq!(*cursor.get(), 'c');
+        assert_eq!(cursor.remove_current(), Some('c'));
+        assert_eq!(*cursor.get(), 'b');
+        assert!(cursor.prev());
+        assert_eq!(*cursor.get(), 'a');
+    }
+
+    #[test]
+    fn wrap_reports_movement() {
+        let mut cursor = abc();
+        assert!(cursor.prev());
+        assert_eq!(cursor.tell(), 2);
+        assert!(cursor.next());
+        assert_eq!(cursor.tell(), 0);
+        let mut single = CursorVec::new(1);
+        assert!(!single.next());
+        assert!(!single.prev());
+    }
+
+    #[test]
+    fn iter_from_cursor() {
+        let mut cursor = abc();
+        assert!(cursor.iter_from_cursor().eq(&['a', 'b', 'c']));
+        cursor.prev();
+        assert!(cursor.iter_from_cursor().eq(&['c', 'a', 'b']));
+    }
+
+    #[test]
+    fn sort_keeps_cursor() {
+        let mut cursor = CursorVec::new((3, 'a'));
+        for &item in &[(1, 'b'), (3, 'c'), (2, 'd'), (1, 'e')] {
+            cursor.push(item);
+        }
+        cursor.seek(2);
+        cursor.sort_by_key(|&(key, _)| key);
+        assert_eq!(*cursor.get(), (3, 'c'));
+        assert_eq!(cursor.tell(), 4);
+
+        cursor.seek(1);
+        cursor.sort_by_key_keep_cursor(|&(_, name)| core::cmp::Reverse(name));
+        assert_eq!(*cursor.get(), (1, 'e'));
+        assert_eq!(cursor.tell(), 0);
+    }
+
+    #[test]
+    fn retain_or() {
+        let mut cursor = CursorVec::new(0);
+        for i in 1..6 {
+            cursor.push(i);
+        }
+        cursor.seek(3);
+        cursor.retain_or(|&i| i % 2 == 1, 99);
+        assert!(cursor.iter().eq(&[1, 3, 5]));
+        assert_eq!(*cursor.get(), 3);
+
+        cursor.seek(2);
+        cursor.retain_or(|&i| i != 5, 99);
+        assert_eq!(*cursor.get(), 1);
+
+        cursor.seek(1);
+        cursor.retain_or(|&i| i == 1, 99);
+        assert_eq!(*cursor.get(), 1);
+
+        let mut saturating = cursor.into_mode::<Saturate>();
+        saturating.push(2);
+        saturating.seek(1);
+        saturating.retain_or(|&i| i != 2, 99);
+        assert_eq!(*saturating.get(), 1);
+
+        saturating.retain_or(|_| false, 99);
+        assert_eq!(saturating.len(), 1);
+        assert_eq!(*saturating.get(), 99);
+    }
+
+    #[test]
+    fn swap_current_with() {
+        let mut cursor = abc();
+        cursor.seek(2);
+        assert!(cursor.swap_current_with(0));
+        assert_eq!((cursor.tell(), *cursor.get()), (0, 'c'));
+        assert!(cursor.swap_current_with(0));
+        assert!(!cursor.swap_current_with(3));
+        assert!(cursor.iter().eq(&['c', 'b', 'a']));
+        assert_eq!(cursor.tell(), 0);
+    }
+
+    #[test]
+    fn find_and_seek() {
+        let mut cursor = CursorVec::new("ok");
+        for &line in &["error: a", "ok", "error: b"] {
+            cursor.push(line);
+        }
+        assert!(cursor.find_and_seek(|line| line.starts_with("error")));
+        assert_eq!(cursor.tell(), 1);
+        assert!(cursor.find_and_seek(|line| line.starts_with("error")));
+        assert_eq!(cursor.tell(), 3);
+        assert!(cursor.find_and_seek(|line| line.starts_with("error")));
+        assert_eq!(cursor.tell(), 1);
+        assert!(cursor.find_and_seek(|&line| line == "error: a"));
+        assert_eq!(cursor.tell(), 1);
+        assert!(!cursor.find_and_seek(|line| line.is_empty()));
+        assert_eq!(cursor.tell(), 1);
+    }
+
+    #[test]
+    fn get_offset() {
+        let mut cursor = abc();
+        cursor.next();
+        assert_eq!(*cursor.get_offset(0), 'b');
+        assert_eq!(*cursor.get_offset(1), 'c');
+        assert_eq!(*cursor.get_offset(2), 'a');
+        assert_eq!(*cursor.get_offset(-1), 'a');
+        assert_eq!(*cursor.get_offset(-5), 'c');
+        assert_eq!(*cursor.get_offset(isize::MIN), 'c');
+        assert_eq!(*cursor.get_offset(isize::MAX), 'c');
+    }
+
+    #[test]
+    fn from_vec() {
+        assert!(CursorVec::<u8>::from_vec(Vec::new()).is_none());
+        assert!(CursorVec::<u8>::from_slice(&[]).is_none());
+
+        let cursor = CursorVec::from_vec(Vec::from([1, 2, 3])).unwrap();
+        assert_eq!(cursor.tell(), 0);
+        assert!(cursor.iter().eq(&[1, 2, 3]));
+
+        let strings = [String::from("a"), String::from("b")];
+        let cursor = CursorVec::from_slice(&strings).unwrap();
+        assert_eq!(cursor.len(), 2);
+        assert_eq!(*cursor.get(), "a");
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        // The first element is always there, so 7 is read as a "more" flag
+        let mut u = Unstructured::new(&[1, 7, 1, 8, 0]);
+        let cursor = CursorVec::<u8>::arbitrary(&mut u).unwrap();
+        assert!(cursor.iter().eq(&[1, 1]));
+        assert_eq!(cursor.tell(), 0);
+
+        // With no data left, the first element is still generated, as a 0
+        let mut u = Unstructured::new(&[]);
+        let cursor = CursorVec::<u8>::arbitrary(&mut u).unwrap();
+        assert!(cursor.iter().eq(&[0]));
+    }
+}
@@ -0,0 +1,262 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, FusedIterator};
+use vec::ChillVec as Vec;
+
+/// A list of slices stored in one allocation, like `StrVec` but for any element type.
+///
+/// All the elements live back to back in one buffer, and a second buffer records where each slice
+/// ends, so pushing a slice never allocates a new buffer just for it.
+///
+/// ```
+/// # use panicless::FlatVec;
+/// let mut features = FlatVec::new();
+/// features.push(&[0.5f32, 1.0]);
+/// features.push(&[]);
+/// features.push(&[0.25]);
+/// assert_eq!(features.len(), 3);
+/// assert_eq!(features.get(0), Some(&[0.5, 1.0][..]));
+/// assert_eq!(features.get(3), None);
+/// ```
+#[derive(Clone)]
+pub struct FlatVec<T> {
+    // StrVec works on these directly for the operations that need to move bytes around
+    pub(crate) data: Vec<T>,
+    // indices[i]..indices[i + 1] is the range in data of the slice at i. Always starts with a 0.
+    pub(crate) indices: Vec<usize>,
+}
+
+/// A list of byte strings stored in one allocation: a `StrVec` without the UTF-8 requirement.
+///
+/// ```
+/// # use panicless::BytesVec;
+/// let mut packets = BytesVec::new();
+/// packets.push(b"\x00\xff\x10");
+/// packets.push(b"GET / HTTP/1.1");
+/// assert_eq!(packets.get(0), Some(&b"\x00\xff\x10"[..]));
+/// assert_eq!(packets.iter().map(|p| p.len()).sum::<usize>(), 17);
+/// ```
+pub type BytesVec = FlatVec<u8>;
+
+pub struct FlatVecIter<'a, T> {
+    slices: &'a FlatVec<T>,
+    // The not-yet-yielded slices are those in index..end
+    index: usize,
+    end: usize,
+}
+
+impl<'a, T> Iterator for FlatVecIter<'a, T> {
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.index += 1;
+            self.slices.get(self.index - 1)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for FlatVecIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            self.slices.get(self.end)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for FlatVecIter<'a, T> {}
+
+impl<'a, T> FusedIterator for FlatVecIter<'a, T> {}
+
+impl<T> Default for FlatVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for FlatVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for FlatVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.indices == other.indices && self.data == other.data
+    }
+}
+
+impl<T: Eq> Eq for FlatVec<T> {}
+
+impl<T: Hash> Hash for FlatVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for slice in self.iter() {
+            slice.hash(state);
+        }
+    }
+}
+
+impl<'a, T: Clone> Extend<&'a [T]> for FlatVec<T> {
+    fn extend<I: IntoIterator<Item = &'a [T]>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.indices.reserve(iter.size_hint().0);
+        for slice in iter {
+            self.push(slice);
+        }
+    }
+}
+
+impl<'a, T: Clone> FromIterator<&'a [T]> for FlatVec<T> {
+    fn from_iter<I: IntoIterator<Item = &'a [T]>>(iter: I) -> Self {
+        let mut slices = FlatVec::new();
+        slices.extend(iter);
+        slices
+    }
+}
+
+impl<T> FlatVec<T> {
+    pub fn new() -> Self {
+        let mut indices = Vec::with_capacity(8);
+        indices.push(0);
+        FlatVec {
+            data: Vec::new(),
+            indices,
+        }
+    }
+
+    /// Creates an empty FlatVec with room for `elements_cap` elements across `slices_cap` slices.
+    pub fn with_capacity(elements_cap: usize, slices_cap: usize) -> Self {
+        let mut indices = Vec::with_capacity(slices_cap.saturating_add(1));
+        indices.push(0);
+        FlatVec {
+            data: Vec::with_capacity(elements_cap),
+            indices,
+        }
+    }
+
+    /// The number of bytes allocated on the heap for both the elements and the slice boundaries,
+    /// including unused capacity.
+    pub fn heap_size(&self) -> usize {
+        self.data.heap_size() + self.indices.heap_size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of slices
+    pub fn len(&self) -> usize {
+        self.indices.len() - 1
+    }
+
+    /// The total number of elements in all the slices
+    pub fn total_len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&[T]> {
+        let begin = *self.indices.get(index)?;
+        let end = *self.indices.get(index + 1)?;
+        self.data.get(begin..end)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut [T]> {
+        let begin = *self.indices.get(index)?;
+        let end = *self.indices.get(index + 1)?;
+        self.data.get_mut(begin..end)
+    }
+
+    pub fn first(&self) -> Option<&[T]> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Option<&[T]> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    /// Keeps the first `len` slices and drops the rest, keeping the allocated capacity.
+    /// Does nothing if there are already no more than `len` slices.
+    pub fn truncate(&mut self, len: usize) {
+        if let Some(&end) = self.indices.get(len) {
+            self.data.truncate(end);
+            self.indices.truncate(len + 1);
+        }
+    }
+
+    /// Removes all the slices, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn iter(&self) -> FlatVecIter<'_, T> {
+        FlatVecIter {
+            slices: self,
+            index: 0,
+            end: self.len(),
+        }
+    }
+}
+
+impl<T: Clone> FlatVec<T> {
+    /// Appends a copy of `slice` as a new entry.
+    pub fn push(&mut self, slice: &[T]) {
+        self.data.extend_from_slice(slice);
+        self.indices.push(self.data.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::v1::*;
+
+    #[test]
+    fn push_get() {
+        let mut slices = FlatVec::new();
+        assert!(slices.is_empty());
+        assert_eq!(slices.first(), None);
+        slices.push(&[1, 2, 3]);
+        slices.push(&[]);
+        slices.push(&[4]);
+        assert_eq!(slices.len(), 3);
+        assert_eq!(slices.total_len(), 4);
+        assert_eq!(slices.get(0), Some(&[1, 2, 3][..]));
+        assert_eq!(slices.get(1), Some(&[][..]));
+        assert_eq!(slices.last(), Some(&[4][..]));
+        assert_eq!(slices.get(3), None);
+
+        slices.get_mut(0).unwrap()[1] = 20;
+        assert_eq!(slices.first(), Some(&[1, 20, 3][..]));
+
+        slices.truncate(1);
+        assert_eq!(slices.len(), 1);
+        assert_eq!(slices.total_len(), 3);
+        slices.clear();
+        assert!(slices.is_empty());
+    }
+
+    #[test]
+    fn iter() {
+        let pieces: [&[String]; 3] = [
+            &[String::from("a")],
+            &[],
+            &[String::from("b"), String::from("c")],
+        ];
+        let slices: FlatVec<String> = pieces.iter().cloned().collect();
+        assert!(slices.iter().eq(pieces.iter().cloned()));
+        assert!(slices.iter().rev().eq(pieces.iter().rev().cloned()));
+        assert_eq!(slices.iter().len(), 3);
+        assert_eq!(format!("{:?}", slices), r#"[["a"], [], ["b", "c"]]"#);
+        assert_eq!(slices.clone(), slices);
+    }
+}
@@ -0,0 +1,232 @@
+use alloc::string::String;
+use core::fmt;
+use core::iter::FromIterator;
+use str_vec::StrVec;
+use vec::ChillVec;
+
+// How many strings share one block. Each block starts with a whole string, so a lookup decodes at
+// most this many strings, and a larger block only saves the occasional full string.
+const BLOCK_LEN: usize = 16;
+
+/// A list of strings stored with front coding: each string after the first in a block stores
+/// only how many leading bytes it shares with the previous string, and the bytes after that.
+///
+/// Sorted or otherwise similar strings, like file paths or log lines, take much less space than
+/// in a `StrVec`. The price is that strings can't be borrowed directly; they are decoded on
+/// demand into a scratch buffer provided by the caller.
+///
+/// ```
+/// # use panicless::FrontCodedStrVec;
+/// let paths: FrontCodedStrVec = ["/usr/bin/cargo", "/usr/bin/rustc", "/usr/lib/libc.so"]
+///     .iter()
+///     .cloned()
+///     .collect();
+/// let mut scratch = String::new();
+/// assert_eq!(paths.get(1, &mut scratch), Some("/usr/bin/rustc"));
+/// assert_eq!(paths.get(3, &mut scratch), None);
+/// ```
+#[derive(Clone, Default)]
+pub struct FrontCodedStrVec {
+    // Each string is a LEB128 shared prefix length, a LEB128 suffix length, then the suffix
+    data: ChillVec<u8>,
+    // The offset in data where each block starts
+    blocks: ChillVec<usize>,
+    len: usize,
+    // The most recently pushed string, to compute the next shared prefix from
+    last: String,
+}
+
+impl fmt::Debug for FrontCodedStrVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut list = f.debug_list();
+        self.for_each(|s| {
+            list.entry(&s);
+        });
+        list.finish()
+    }
+}
+
+impl<'a> Extend<&'a str> for FrontCodedStrVec {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push(s);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for FrontCodedStrVec {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut strings = FrontCodedStrVec::new();
+        strings.extend(iter);
+        strings
+    }
+}
+
+impl FrontCodedStrVec {
+    pub fn new() -> Self {
+        FrontCodedStrVec {
+            data: ChillVec::new(),
+            blocks: ChillVec::new(),
+            len: 0,
+            last: String::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The number of bytes allocated on the heap, including unused capacity.
+    pub fn heap_size(&self) -> usize {
+        self.data.heap_size() + self.blocks.heap_size() + self.last.capacity()
+    }
+
+    pub fn push(&mut self, s: &str) {
+        let prefix = if self.len.is_multiple_of(BLOCK_LEN) {
+            self.blocks.push(self.data.len());
+            0
+        } else {
+            shared_prefix(&self.last, s)
+        };
+        let suffix = s.get(prefix..).unwrap_or(s);
+        write_varint(&mut self.data, s.len() - suffix.len());
+        write_varint(&mut self.data, suffix.len());
+        self.data.extend_from_slice(suffix.as_bytes());
+        self.last.clear();
+        self.last.push_str(s);
+        self.len += 1;
+    }
+
+    /// Decodes the string at `index` into `scratch`, returning it, or returns `None` if the index
+    /// is out of bounds. Anything already in `scratch` is overwritten.
+    pub fn get<'s>(&self, index: usize, scratch: &'s mut String) -> Option<&'s str> {
+        if index >= self.len {
+            return None;
+        }
+        let mut pos = *self.blocks.get(index / BLOCK_LEN)?;
+        scratch.clear();
+        for _ in 0..=index % BLOCK_LEN {
+            self.decode_next(&mut pos, scratch)?;
+        }
+        Some(scratch.as_str())
+    }
+
+    /// Calls `f` with each string in order, decoding them one after another.
+    pub fn for_each<F: FnMut(&str)>(&self, mut f: F) {
+        let mut scratch = String::new();
+        let mut pos = 0;
+        for _ in 0..self.len {
+            if self.decode_next(&mut pos, &mut scratch).is_none() {
+                break;
+            }
+            f(&scratch);
+        }
+    }
+
+    /// Decodes every string into a `StrVec`.
+    pub fn to_str_vec(&self) -> StrVec {
+        let mut strings = StrVec::new();
+        self.for_each(|s| strings.push(s));
+        strings
+    }
+
+    /// Replaces the string in `scratch` with the one encoded at `pos`, which must be either the
+    /// string after it or the start of a block, and advances `pos` past it. Only returns `None` if
+    /// the encoding is corrupt, which it shouldn't ever be.
+    fn decode_next(&self, pos: &mut usize, scratch: &mut String) -> Option<()> {
+        let prefix = read_varint(&self.data, pos)?;
+        let suffix_len = read_varint(&self.data, pos)?;
+        let end = pos.checked_add(suffix_len)?;
+        let suffix = self.data.get(*pos..end)?;
+        if !scratch.is_char_boundary(prefix) {
+            return None;
+        }
+        scratch.truncate(prefix);
+        // push only splits strings at char boundaries, so every suffix is the UTF-8 tail of a str
+        scratch.push_str(unsafe { core::str::from_utf8_unchecked(suffix) });
+        *pos = end;
+        Some(())
+    }
+}
+
+/// The length of the longest common prefix of `a` and `b` that ends on a char boundary
+fn shared_prefix(a: &str, b: &str) -> usize {
+    let mut len = a.bytes().zip(b.bytes()).take_while(|(a, b)| a == b).count();
+    while !b.is_char_boundary(len) {
+        len -= 1;
+    }
+    len
+}
+
+fn write_varint(data: &mut ChillVec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        data.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    data.push(value as u8);
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Option<usize> {
+    let mut value = 0usize;
+    let mut shift = 0;
+    loop {
+        let byte = *data.get(*pos)?;
+        *pos += 1;
+        value |= usize::from(byte & 0x7f).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::v1::*;
+
+    #[test]
+    fn round_trip() {
+        let paths: Vec<String> = (0..100)
+            .map(|i| format!("/home/user/src/project/module_{}/file_{}.rs", i / 10, i))
+            .collect();
+        let front_coded: FrontCodedStrVec = paths.iter().map(|s| &s[..]).collect();
+        assert_eq!(front_coded.len(), 100);
+
+        let mut scratch = String::new();
+        for (i, path) in paths.iter().enumerate() {
+            assert_eq!(front_coded.get(i, &mut scratch), Some(&path[..]));
+        }
+        assert_eq!(front_coded.get(100, &mut scratch), None);
+        assert_eq!(front_coded.to_str_vec(), paths);
+
+        let total: usize = paths.iter().map(|s| s.len()).sum();
+        assert!(front_coded.data.len() < total / 2);
+    }
+
+    #[test]
+    fn char_boundaries() {
+        // "é" and "è" share their first byte, which must not be split off as a prefix
+        let strings = ["", "aé", "aè", "aè", "a", "ab", "é"];
+        let front_coded: FrontCodedStrVec = strings.iter().cloned().collect();
+        assert_eq!(front_coded.to_str_vec(), strings);
+        assert_eq!(format!("{:?}", front_coded), format!("{:?}", strings));
+    }
+
+    #[test]
+    fn varint() {
+        let mut data = ChillVec::new();
+        for &value in &[0, 1, 0x7f, 0x80, 300, usize::MAX] {
+            data.clear();
+            write_varint(&mut data, value);
+            let mut pos = 0;
+            assert_eq!(read_varint(&data, &mut pos), Some(value));
+            assert_eq!(pos, data.len());
+        }
+        assert_eq!(read_varint(&[0x80], &mut 0), None);
+    }
+}
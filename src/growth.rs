@@ -0,0 +1,38 @@
+/// Decides how much a `ChillVec` allocates when it runs out of room.
+///
+/// `reserve` (and therefore `push`) allocates whatever `grow` returns, or `required` if that is
+/// larger. `reserve_exact` ignores the strategy and always allocates exactly `required`.
+pub trait GrowthStrategy {
+    /// Returns the new capacity for a vector with room for `capacity` elements that needs room
+    /// for `required`, which is always larger than `capacity`.
+    fn grow(capacity: usize, required: usize) -> usize;
+}
+
+/// At least doubles the capacity on every reallocation, so pushing n elements one at a time only
+/// reallocates O(log n) times. This is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Doubling;
+
+impl GrowthStrategy for Doubling {
+    #[inline]
+    fn grow(capacity: usize, required: usize) -> usize {
+        // Skip the tiny sizes, where reallocating is all overhead
+        const MIN_NON_ZERO_CAPACITY: usize = 4;
+        capacity
+            .saturating_mul(2)
+            .max(required)
+            .max(MIN_NON_ZERO_CAPACITY)
+    }
+}
+
+/// Never allocates more than is required. This keeps memory use to a minimum, but pushing
+/// elements one at a time reallocates on every push.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Exact;
+
+impl GrowthStrategy for Exact {
+    #[inline]
+    fn grow(_capacity: usize, required: usize) -> usize {
+        required
+    }
+}
@@ -0,0 +1,200 @@
+use core::convert::TryFrom;
+use str_vec::StrVec;
+use vec::{capacity_overflow, ChillVec};
+
+/// A handle to a string stored in an `Interner`. Symbols from the same interner are equal
+/// exactly when their strings are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+// Marks an unused slot in the hash table. No symbol can have this value, see get_or_intern.
+const EMPTY: u32 = u32::MAX;
+
+/// Stores one copy of each distinct string it is given, handing out a `Symbol` for each.
+///
+/// The strings themselves live in a `StrVec`, in the order they were first interned, so the
+/// symbol is just the string's index. Lookups by string go through an open-addressing hash table
+/// of symbols.
+///
+/// ```
+/// # use panicless::Interner;
+/// let mut interner = Interner::new();
+/// let foo = interner.get_or_intern("foo");
+/// let bar = interner.get_or_intern("bar");
+/// assert_eq!(interner.get_or_intern("foo"), foo);
+/// assert_ne!(foo, bar);
+/// assert_eq!(interner.resolve(bar), Some("bar"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    strings: StrVec,
+    // A power of two in length, or empty before anything is interned
+    slots: ChillVec<u32>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Interner {
+            strings: StrVec::new(),
+            slots: ChillVec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of distinct strings interned
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns the string `symbol` stands for, or `None` if it came from another interner that
+    /// has more strings.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.strings.get(symbol.0 as usize)
+    }
+
+    /// Returns the symbol for `s` if it has been interned.
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        if self.slots.is_empty() {
+            return None;
+        }
+        self.probe(s).ok()
+    }
+
+    /// Returns the symbol for `s`, interning it first if this is the first time it's been seen.
+    ///
+    /// Aborts once there are `u32::MAX` strings, since the handles would run out.
+    pub fn get_or_intern(&mut self, s: &str) -> Symbol {
+        let mut slot = None;
+        if !self.slots.is_empty() {
+            match self.probe(s) {
+                Ok(symbol) => return symbol,
+                Err(empty) => slot = Some(empty),
+            }
+        }
+
+        // Keep the table at most 3/4 full so probe sequences stay short. Growing moves every
+        // symbol, so the empty slot has to be found again.
+        let full =
+            self.len().saturating_add(1).saturating_mul(4) > self.slots.len().saturating_mul(3);
+        let slot = match slot {
+            Some(slot) if !full => slot,
+            _ => {
+                self.grow();
+                match self.probe(s) {
+                    Ok(symbol) => return symbol,
+                    Err(slot) => slot,
+                }
+            }
+        };
+
+        let symbol = match u32::try_from(self.len()) {
+            Ok(symbol) if symbol != EMPTY => symbol,
+            _ => capacity_overflow::<u32>(),
+        };
+        self.strings.push(s);
+        self.slots[slot] = symbol;
+        Symbol(symbol)
+    }
+
+    /// Finds the symbol for `s`, or the empty slot where it belongs. The table must not be empty.
+    fn probe(&self, s: &str) -> Result<Symbol, usize> {
+        let mask = self.slots.len() - 1;
+        let mut slot = hash(s) & mask;
+        loop {
+            let symbol = self.slots[slot];
+            if symbol == EMPTY {
+                return Err(slot);
+            }
+            if self.strings.get(symbol as usize) == Some(s) {
+                return Ok(Symbol(symbol));
+            }
+            slot = (slot + 1) & mask;
+        }
+    }
+
+    /// Doubles the size of the hash table and puts every symbol back in it
+    fn grow(&mut self) {
+        let new_len = self.slots.len().saturating_mul(2).max(8);
+        let mask = new_len - 1;
+        self.slots = ChillVec::from_elem(EMPTY, new_len);
+        for (symbol, s) in self.strings.iter().enumerate() {
+            let mut slot = hash(s) & mask;
+            while self.slots[slot] != EMPTY {
+                slot = (slot + 1) & mask;
+            }
+            self.slots[slot] = symbol as u32;
+        }
+    }
+}
+
+/// FNV-1a, which is quick for short strings and doesn't need std's RandomState
+fn hash(s: &str) -> usize {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &byte in s.as_bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::v1::*;
+
+    #[test]
+    fn intern() {
+        let mut interner = Interner::new();
+        assert!(interner.is_empty());
+        assert_eq!(interner.get("a"), None);
+
+        let a = interner.get_or_intern("a");
+        let empty = interner.get_or_intern("");
+        assert_eq!(interner.get_or_intern("a"), a);
+        assert_eq!(interner.get(""), Some(empty));
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.resolve(a), Some("a"));
+        assert_eq!(interner.resolve(empty), Some(""));
+        assert_eq!(interner.resolve(Symbol(2)), None);
+        assert_eq!(a.as_u32(), 0);
+    }
+
+    #[test]
+    fn many() {
+        let mut interner = Interner::new();
+        let words: Vec<String> = (0..1000).map(|i| format!("word{}", i % 300)).collect();
+        let symbols: Vec<Symbol> = words.iter().map(|w| interner.get_or_intern(w)).collect();
+        assert_eq!(interner.len(), 300);
+        for (word, &symbol) in words.iter().zip(&symbols) {
+            assert_eq!(interner.resolve(symbol), Some(&word[..]));
+            assert_eq!(interner.get(word), Some(symbol));
+        }
+        assert_eq!(symbols[0], symbols[300]);
+        assert_eq!(interner.get("word300"), None);
+        assert!(interner.slots.len() * 3 >= interner.len() * 4);
+    }
+
+    #[test]
+    fn lookup_does_not_grow() {
+        let mut interner = Interner::new();
+        for word in ["a", "b", "c", "d", "e", "f"].iter() {
+            interner.get_or_intern(word);
+        }
+        assert_eq!(interner.slots.len(), 8);
+        // The table is as full as it gets, but strings it already has don't need more room
+        assert_eq!(interner.get_or_intern("a"), Symbol(0));
+        assert_eq!(interner.slots.len(), 8);
+        assert_eq!(interner.get_or_intern("g"), Symbol(6));
+        assert_eq!(interner.slots.len(), 16);
+        assert_eq!(interner.get("f"), Some(Symbol(5)));
+    }
+}
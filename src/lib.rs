@@ -1,10 +1,71 @@
+#![no_std]
+#![cfg_attr(
+    feature = "nightly",
+    feature(dropck_eyepatch, min_specialization, trusted_len)
+)]
+
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(feature = "serde", test))]
+extern crate serde_json;
+
+#[macro_use]
+mod macros;
+
+mod c_str_vec;
+pub use c_str_vec::{CStrArray, CStrVec};
+
+#[cfg(feature = "std")]
+mod chill_cursor;
+#[cfg(feature = "std")]
+pub use chill_cursor::ChillCursor;
+
+mod cursor_mode;
+pub use cursor_mode::{CursorMode, Saturate, Wrap};
+
 mod cursor_vec;
 pub use cursor_vec::CursorVec;
 
+#[cfg(feature = "front-coding")]
+mod front_coded;
+#[cfg(feature = "front-coding")]
+pub use front_coded::FrontCodedStrVec;
+
+mod growth;
+pub use growth::{Doubling, Exact, GrowthStrategy};
+
+mod interner;
+pub use interner::{Interner, Symbol};
+
+mod oom;
+pub use oom::set_oom_hook;
+
+#[cfg(feature = "std")]
+mod path_vec;
+#[cfg(feature = "std")]
+pub use path_vec::PathVec;
+
 mod str_vec;
-pub use str_vec::StrVec;
+pub use str_vec::{StrVec, StrVecDrain, StrVecIter, StrVecSlice};
+
+mod str_vec_view;
+pub use str_vec_view::StrVecView;
+
+#[cfg(feature = "proptest")]
+pub mod testing;
 
 mod vec;
-pub use vec::ChillVec;
+pub use vec::{AllocError, ChillDrain, ChillExtractIf, ChillIntoIter, ChillSplice, ChillVec};
 
 mod flat;
+pub use flat::{BytesVec, FlatVec, FlatVecIter};
@@ -0,0 +1,28 @@
+/// Creates a `ChillVec` containing the arguments, like `vec!`.
+///
+/// `chillvec![x; n]` clones `x` into `n` slots, allocating exactly once.
+///
+/// ```
+/// #[macro_use]
+/// extern crate panicless;
+///
+/// # fn main() {
+/// let vec = chillvec![1, 2, 3];
+/// assert_eq!(vec, [1, 2, 3]);
+///
+/// let zeroes = chillvec![0u8; 4];
+/// assert_eq!(zeroes, [0, 0, 0, 0]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! chillvec {
+    () => {
+        $crate::ChillVec::new()
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::ChillVec::from_elem($elem, $n)
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::ChillVec::from([$($x),+])
+    };
+}
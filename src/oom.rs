@@ -0,0 +1,59 @@
+use alloc::alloc::Layout;
+use core::mem;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+// A fn(Layout), or null if no hook is set. Function pointers can't go in an atomic directly.
+static OOM_HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers a function to be called with the failing `Layout` just before the process aborts
+/// because an allocation failed. This is the place to log the size of the allocation or flush
+/// telemetry; it does not get to recover.
+///
+/// Only one hook is kept, setting a new one replaces the previous hook.
+///
+/// ```
+/// # use std::alloc::Layout;
+/// fn report(layout: Layout) {
+///     eprintln!("out of memory allocating {} bytes", layout.size());
+/// }
+///
+/// panicless::set_oom_hook(report);
+/// ```
+pub fn set_oom_hook(hook: fn(Layout)) {
+    OOM_HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+fn run_oom_hook(layout: Layout) {
+    let hook = OOM_HOOK.load(Ordering::Acquire);
+    if !hook.is_null() {
+        // Only ever set from a fn(Layout) in set_oom_hook
+        let hook: fn(Layout) = unsafe { mem::transmute(hook) };
+        hook(layout);
+    }
+}
+
+/// Runs the OOM hook, then aborts via the global allocation error handler
+pub(crate) fn handle_alloc_error(layout: Layout) -> ! {
+    run_oom_hook(layout);
+    alloc::alloc::handle_alloc_error(layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::AtomicUsize;
+
+    static REPORTED: AtomicUsize = AtomicUsize::new(0);
+
+    fn record(layout: Layout) {
+        REPORTED.store(layout.size(), Ordering::SeqCst);
+    }
+
+    #[test]
+    fn hook_sees_layout() {
+        set_oom_hook(record);
+        run_oom_hook(Layout::from_size_align(1234, 8).unwrap());
+        assert_eq!(REPORTED.load(Ordering::SeqCst), 1234);
+    }
+}
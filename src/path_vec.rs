@@ -0,0 +1,136 @@
+use core::fmt;
+use core::iter::FromIterator;
+use flat::BytesVec;
+use std::ffi::OsStr;
+use std::path::Path;
+
+/// A list of paths stored in one allocation, for keeping the results of a directory walk without
+/// a `PathBuf` allocation per path.
+///
+/// ```
+/// # use panicless::PathVec;
+/// # use std::path::Path;
+/// let mut paths = PathVec::new();
+/// paths.push(Path::new("src/lib.rs"));
+/// paths.push(Path::new("Cargo.toml"));
+/// assert_eq!(paths.get(1), Some(Path::new("Cargo.toml")));
+/// assert_eq!(paths.get(2), None);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct PathVec {
+    // Every entry is the encoded bytes of an OsStr
+    paths: BytesVec,
+}
+
+impl fmt::Debug for PathVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> Extend<&'a Path> for PathVec {
+    fn extend<I: IntoIterator<Item = &'a Path>>(&mut self, iter: I) {
+        for path in iter {
+            self.push(path);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a Path> for PathVec {
+    fn from_iter<I: IntoIterator<Item = &'a Path>>(iter: I) -> Self {
+        let mut paths = PathVec::new();
+        paths.extend(iter);
+        paths
+    }
+}
+
+impl PathVec {
+    pub fn new() -> Self {
+        PathVec {
+            paths: BytesVec::new(),
+        }
+    }
+
+    /// Creates an empty PathVec with room for `bytes_cap` bytes of paths, across `paths_cap`
+    /// paths.
+    pub fn with_capacity(bytes_cap: usize, paths_cap: usize) -> Self {
+        PathVec {
+            paths: BytesVec::with_capacity(bytes_cap, paths_cap),
+        }
+    }
+
+    /// The number of bytes allocated on the heap, including unused capacity.
+    pub fn heap_size(&self) -> usize {
+        self.paths.heap_size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn push(&mut self, path: &Path) {
+        self.paths.push(path.as_os_str().as_encoded_bytes());
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Path> {
+        self.paths.get(index).map(to_path)
+    }
+
+    /// Keeps the first `len` paths and removes the rest, keeping the allocated capacity.
+    pub fn truncate(&mut self, len: usize) {
+        self.paths.truncate(len);
+    }
+
+    /// Removes all the paths, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.paths.clear();
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Path> + ExactSizeIterator + '_ {
+        self.paths.iter().map(to_path)
+    }
+}
+
+fn to_path(bytes: &[u8]) -> &Path {
+    // Only push adds entries, and it stores the bytes of an OsStr from this same program
+    Path::new(unsafe { OsStr::from_encoded_bytes_unchecked(bytes) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::prelude::v1::*;
+
+    #[test]
+    fn push_get() {
+        let paths: Vec<PathBuf> = ["/", "", "a/b/c.txt", "../d"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        let path_vec: PathVec = paths.iter().map(|p| p.as_path()).collect();
+        assert_eq!(path_vec.len(), 4);
+        for (i, path) in paths.iter().enumerate() {
+            assert_eq!(path_vec.get(i), Some(path.as_path()));
+        }
+        assert!(path_vec
+            .iter()
+            .rev()
+            .eq(paths.iter().rev().map(|p| p.as_path())));
+        assert_eq!(format!("{:?}", path_vec), format!("{:?}", paths));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+        let path = Path::new(OsStr::from_bytes(b"caf\xe9"));
+        let mut paths = PathVec::new();
+        paths.push(path);
+        assert_eq!(paths.get(0), Some(path));
+    }
+}
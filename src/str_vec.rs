@@ -1,4 +1,14 @@
+use alloc::string::String;
+use alloc::vec::Vec as StdVec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{self, FromIterator, FusedIterator};
+use core::ops::{Range, RangeBounds};
+use core::ptr;
+use flat::FlatVec;
 use vec::ChillVec as Vec;
+use vec::{checked_range, AllocError};
 
 // One might expect this to be backed by a String, but to do so would not make this code panicless
 // String is backed by a RawVec, which can panic when it expands its allocation if the allocation
@@ -12,86 +22,1187 @@ use vec::ChillVec as Vec;
 /// outperform a `Vec<String>` for operations that iterate over the collection.
 /// A StrVec may have less memory overhead than a Vec<String>, as each std::string::String must
 /// store 3 pointer-size ints along with its data a StrVec only stores one.
+///
+/// The storage is a `FlatVec<u8>`; a StrVec only adds the guarantee that every entry is UTF-8.
+#[derive(Clone)]
 pub struct StrVec {
-    data: Vec<u8>,
-    indices: Vec<usize>,
+    flat: FlatVec<u8>,
+}
+
+/// A borrowed view of a contiguous range of the strings in a `StrVec`, from `StrVec::slice`.
+#[derive(Clone, Copy)]
+pub struct StrVecSlice<'a> {
+    data: &'a [u8],
+    // One more than the number of strings. These are offsets into the whole of data, so the first
+    // one usually isn't 0.
+    indices: &'a [usize],
+}
+
+impl<'a> StrVecSlice<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len() - 1
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a str> {
+        let begin = *self.indices.get(index)?;
+        let end = *self.indices.get(index + 1)?;
+        self.data
+            .get(begin..end)
+            .map(|b| unsafe { core::str::from_utf8_unchecked(b) })
+    }
+
+    pub fn iter(&self) -> StrVecIter<'a> {
+        StrVecIter {
+            strings: *self,
+            index: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Narrows the view further to the strings in `range`, or returns `None` if it is out of
+    /// bounds.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Option<StrVecSlice<'a>> {
+        let range = checked_range(range, self.len())?;
+        Some(StrVecSlice {
+            data: self.data,
+            indices: self.indices.get(range.start..range.end + 1)?,
+        })
+    }
+}
+
+impl<'a> fmt::Debug for StrVecSlice<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
 }
 
 pub struct StrVecIter<'a> {
-    strvec: &'a StrVec,
+    strings: StrVecSlice<'a>,
+    // The not-yet-yielded strings are those in index..end
     index: usize,
+    end: usize,
 }
 
 impl<'a> Iterator for StrVecIter<'a> {
     type Item = &'a str;
     fn next(&mut self) -> Option<Self::Item> {
-        let out = if self.index < self.len() {
-            self.strvec.get(self.index)
+        if self.index < self.end {
+            self.index += 1;
+            self.strings.get(self.index - 1)
         } else {
             None
-        };
-        self.index += 1;
-        out
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
     }
 }
 
-impl<'a> ExactSizeIterator for StrVecIter<'a> {
-    fn len(&self) -> usize {
-        self.strvec.len()
+impl<'a> DoubleEndedIterator for StrVecIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            self.strings.get(self.end)
+        } else {
+            None
+        }
     }
 }
 
+impl<'a> ExactSizeIterator for StrVecIter<'a> {}
+
+impl<'a> FusedIterator for StrVecIter<'a> {}
+
 impl Default for StrVec {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl fmt::Debug for StrVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Two StrVecs hold the same strings exactly when their buffers match, the indices are what
+// separate one string from the next
+impl PartialEq for StrVec {
+    fn eq(&self, other: &StrVec) -> bool {
+        self.flat == other.flat
+    }
+}
+
+impl Eq for StrVec {}
+
+impl<'a> PartialEq<[&'a str]> for StrVec {
+    fn eq(&self, other: &[&'a str]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == *b)
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b [&'a str]> for StrVec {
+    fn eq(&self, other: &&'b [&'a str]) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a, const N: usize> PartialEq<[&'a str; N]> for StrVec {
+    fn eq(&self, other: &[&'a str; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl PartialEq<StdVec<String>> for StrVec {
+    fn eq(&self, other: &StdVec<String>) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl PartialOrd for StrVec {
+    fn partial_cmp(&self, other: &StrVec) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Lexicographic over the strings, like a slice of them
+impl Ord for StrVec {
+    fn cmp(&self, other: &StrVec) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl Hash for StrVec {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for s in self.iter() {
+            s.hash(state);
+        }
+    }
+}
+
+impl<'a> Extend<&'a str> for StrVec {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        // The iterator can tell us how many strings are coming but not how long they are
+        self.flat.indices.reserve(iter.size_hint().0);
+        for s in iter {
+            self.push(s);
+        }
+    }
+}
+
+impl Extend<String> for StrVec {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.flat.indices.reserve(iter.size_hint().0);
+        for s in iter {
+            self.push(&s);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for StrVec {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut strings = StrVec::new();
+        strings.extend(iter);
+        strings
+    }
+}
+
+impl FromIterator<String> for StrVec {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut strings = StrVec::new();
+        strings.extend(iter);
+        strings
+    }
+}
+
+impl<'a> From<&'a [&'a str]> for StrVec {
+    fn from(strings: &'a [&'a str]) -> Self {
+        StrVec::from_strs(strings)
+    }
+}
+
+impl From<StdVec<String>> for StrVec {
+    fn from(strings: StdVec<String>) -> Self {
+        StrVec::from_strs(&strings)
+    }
+}
+
 impl StrVec {
     pub fn new() -> Self {
-        let mut indices = Vec::with_capacity(8);
-        indices.push(0);
         StrVec {
-            data: Vec::with_capacity(64),
-            indices,
+            flat: FlatVec::with_capacity(64, 7),
         }
     }
 
+    /// Collects `strings` with exactly enough room for all of them
+    fn from_strs<S: AsRef<str>>(strings: &[S]) -> Self {
+        let bytes = strings
+            .iter()
+            .fold(0usize, |total, s| total.saturating_add(s.as_ref().len()));
+        let mut collected = StrVec::with_capacity(bytes, strings.len().saturating_add(1));
+        collected.extend(strings.iter().map(|s| s.as_ref()));
+        collected
+    }
+
     pub fn with_capacity(bytes_cap: usize, indices_cap: usize) -> Self {
-        let mut indices = Vec::with_capacity(indices_cap);
-        indices.push(0);
+        // FlatVec counts slices, and adds the index for the leading 0 itself
         StrVec {
-            data: Vec::with_capacity(bytes_cap),
-            indices,
+            flat: FlatVec::with_capacity(bytes_cap, indices_cap.saturating_sub(1)),
         }
     }
 
+    /// Reserves space for `additional_bytes` more bytes of string data and `additional_strings`
+    /// more strings.
+    pub fn reserve(&mut self, additional_bytes: usize, additional_strings: usize) {
+        self.flat.data.reserve(additional_bytes);
+        self.flat.indices.reserve(additional_strings);
+    }
+
+    /// Reserves space for `additional_bytes` more bytes of string data and `additional_strings`
+    /// more strings, reporting allocation failure instead of aborting.
+    pub fn try_reserve(
+        &mut self,
+        additional_bytes: usize,
+        additional_strings: usize,
+    ) -> Result<(), AllocError> {
+        self.flat.data.try_reserve(additional_bytes)?;
+        self.flat.indices.try_reserve(additional_strings)
+    }
+
+    /// The number of bytes allocated on the heap for both the string data and the string
+    /// boundaries, including unused capacity.
+    pub fn heap_size(&self) -> usize {
+        self.flat.heap_size()
+    }
+
+    /// Shrinks both the data and index buffers as close to their lengths as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.flat.data.shrink_to_fit();
+        self.flat.indices.shrink_to_fit();
+    }
+
+    /// How many bytes of string data fit before the data buffer has to reallocate.
+    pub fn data_capacity(&self) -> usize {
+        self.flat.data.capacity()
+    }
+
+    /// How many strings fit before the index buffer has to reallocate.
+    pub fn index_capacity(&self) -> usize {
+        // One index is always taken by the leading 0
+        self.flat.indices.capacity() - 1
+    }
+
+    /// The total length in bytes of all the strings.
+    pub fn total_bytes(&self) -> usize {
+        self.flat.total_len()
+    }
+
+    /// Iterates over the byte length of each string.
+    pub fn lengths(&self) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator + '_ {
+        self.spans().map(|(start, end)| end - start)
+    }
+
+    /// Iterates over the `(start, end)` byte range of each string within the concatenation of
+    /// all of them, as returned by `concat`.
+    pub fn spans(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (usize, usize)> + ExactSizeIterator + '_ {
+        self.flat
+            .indices
+            .iter()
+            .copied()
+            .zip(self.flat.indices.iter().copied().skip(1))
+    }
+
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
+    /// Iterates over each string along with its index and its byte range within the
+    /// concatenation of all the strings, as returned by `concat`.
+    pub fn iter_spans(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (usize, Range<usize>, &str)> + ExactSizeIterator + '_ {
+        self.spans()
+            .zip(self.iter())
+            .enumerate()
+            .map(|(i, ((start, end), s))| (i, start..end, s))
+    }
+
     pub fn get(&self, index: usize) -> Option<&str> {
-        let begin = *self.indices.get(index)?;
-        let end = *self.indices.get(index + 1)?;
-        self.data
-            .get(begin..end)
-            .map(|b| unsafe { std::str::from_utf8_unchecked(b) })
+        self.flat
+            .get(index)
+            .map(|b| unsafe { core::str::from_utf8_unchecked(b) })
     }
 
-    pub fn push(&mut self, item: &str) {
-        self.indices.push(self.data.len() + item.len());
-        self.data.extend_from_slice(item.as_bytes());
+    /// Returns the string at `index` without checking that it's in bounds.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `self.len()`.
+    pub unsafe fn get_unchecked(&self, index: usize) -> &str {
+        debug_assert!(index < self.len());
+        let begin = *self.flat.indices.get_unchecked(index);
+        let end = *self.flat.indices.get_unchecked(index + 1);
+        core::str::from_utf8_unchecked(self.flat.data.get_unchecked(begin..end))
+    }
+
+    /// Returns the index of the first string equal to `s`.
+    pub fn position(&self, s: &str) -> Option<usize> {
+        self.iter().position(|item| item == s)
+    }
+
+    /// Returns the index of the first string equal to `s`, ignoring ASCII case.
+    pub fn position_ignore_ascii_case(&self, s: &str) -> Option<usize> {
+        self.spans().position(|(start, end)| {
+            self.flat
+                .data
+                .get(start..end)
+                .is_some_and(|item| item.eq_ignore_ascii_case(s.as_bytes()))
+        })
+    }
+
+    /// Finds every occurrence of `needle` in the strings, yielding the index of the string it is
+    /// in and its byte offset in that string. Occurrences within one string don't overlap, like
+    /// `str::match_indices`. An empty needle matches nothing.
+    ///
+    /// This searches the whole data buffer at once instead of one string at a time, discarding
+    /// the matches that span two strings.
+    ///
+    /// ```
+    /// # use panicless::StrVec;
+    /// let words: StrVec = ["banana", "an", "nab"].iter().cloned().collect();
+    /// let found: Vec<_> = words.find_all("an").collect();
+    /// assert_eq!(found, [(0, 1), (0, 3), (1, 0)]);
+    /// ```
+    pub fn find_all<'a>(&'a self, needle: &'a str) -> impl Iterator<Item = (usize, usize)> + 'a {
+        // All the strings back to back are still valid UTF-8
+        let haystack = unsafe { core::str::from_utf8_unchecked(&self.flat.data) };
+        let mut pos = 0;
+        let mut entry = 0;
+        iter::from_fn(move || {
+            if needle.is_empty() {
+                return None;
+            }
+            loop {
+                let found = pos + haystack.get(pos..)?.find(needle)?;
+                // Whichever string starts last at or before the match contains its start
+                let later = self.flat.indices.get(entry + 1..)?;
+                entry += later.partition_point(|&start| start <= found);
+                let start = *self.flat.indices.get(entry)?;
+                let end = *self.flat.indices.get(entry + 1)?;
+                if found + needle.len() <= end {
+                    pos = found + needle.len();
+                    return Some((entry, found - start));
+                }
+                // The match runs into the next string, but there might be one that starts later
+                // in this string and overlaps it, so carry on from the next string
+                pos = end;
+            }
+        })
     }
 
-    pub fn iter(&self) -> StrVecIter {
+    pub fn contains(&self, s: &str) -> bool {
+        self.position(s).is_some()
+    }
+
+    pub fn contains_ignore_ascii_case(&self, s: &str) -> bool {
+        self.position_ignore_ascii_case(s).is_some()
+    }
+
+    /// Searches a sorted StrVec for `s`, returning its index if found, or the index where it
+    /// could be inserted to keep the StrVec sorted if not. If the StrVec isn't sorted the
+    /// result is meaningless, but it won't panic.
+    pub fn binary_search(&self, s: &str) -> Result<usize, usize> {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.get(mid).map(|item| item.cmp(s)) {
+                Some(Ordering::Less) => low = mid + 1,
+                Some(Ordering::Greater) => high = mid,
+                Some(Ordering::Equal) => return Ok(mid),
+                // mid is always in bounds
+                None => break,
+            }
+        }
+        Err(low)
+    }
+
+    /// The index of the first string for which `pred` is `false`, assuming it is `true` for
+    /// every string before that and `false` for every one after
+    fn partition_point<P: FnMut(&str) -> bool>(&self, mut pred: P) -> usize {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.get(mid).map(&mut pred) {
+                Some(true) => low = mid + 1,
+                Some(false) => high = mid,
+                // mid is always in bounds
+                None => break,
+            }
+        }
+        low
+    }
+
+    /// Iterates over the strings that start with `prefix`.
+    pub fn iter_with_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.iter().filter(move |s| s.starts_with(prefix))
+    }
+
+    /// Like `iter_with_prefix`, but for a sorted StrVec, where all the strings with a prefix are
+    /// next to each other. Two binary searches find them, instead of checking every string.
+    /// If the StrVec isn't sorted the result is meaningless, but it won't panic.
+    pub fn sorted_iter_with_prefix(&self, prefix: &str) -> StrVecIter<'_> {
+        let start = self.partition_point(|s| s < prefix);
+        let end = self.partition_point(|s| s < prefix || s.starts_with(prefix));
         StrVecIter {
-            strvec: self,
-            index: 0,
+            strings: self.as_slice(),
+            index: start,
+            // Only unsorted input can put the end first
+            end: end.max(start),
+        }
+    }
+
+    pub fn first(&self) -> Option<&str> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Option<&str> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    pub fn push(&mut self, item: &str) {
+        self.flat.push(item.as_bytes());
+    }
+
+    /// Moves all the strings of `other` onto the end of this StrVec, leaving `other` empty. The
+    /// string data is moved with one copy; only the offsets are adjusted one by one.
+    ///
+    /// ```
+    /// # use panicless::StrVec;
+    /// let mut a: StrVec = ["a", "b"].iter().cloned().collect();
+    /// let mut b: StrVec = ["c"].iter().cloned().collect();
+    /// a.append(&mut b);
+    /// assert_eq!(a, ["a", "b", "c"]);
+    /// assert!(b.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut StrVec) {
+        let base = self.flat.data.len();
+        // Moving the data first aborts if the combined length overflows, before any offset can
+        self.flat.data.append(&mut other.flat.data);
+        self.flat.indices.reserve(other.len());
+        self.flat
+            .indices
+            .extend(other.flat.indices.iter().skip(1).map(|&end| base + end));
+        other.clear();
+    }
+
+    /// Replaces the string at `index` with `s`, shifting the strings after it to fit. Returns
+    /// `false` and leaves the StrVec alone if `index` is out of bounds.
+    pub fn replace(&mut self, index: usize, s: &str) -> bool {
+        let (start, end) = match (
+            self.flat.indices.get(index),
+            self.flat.indices.get(index + 1),
+        ) {
+            (Some(&start), Some(&end)) => (start, end),
+            _ => return false,
+        };
+        if self.flat.data.splice(start..end, s.bytes()).is_none() {
+            return false;
+        }
+        for offset in self.flat.indices.iter_mut().skip(index + 1) {
+            *offset = *offset - (end - start) + s.len();
+        }
+        true
+    }
+
+    /// Removes the string at `index`, shifting the strings after it down. Returns `false` and
+    /// leaves the StrVec alone if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (start, end) = match (
+            self.flat.indices.get(index),
+            self.flat.indices.get(index + 1),
+        ) {
+            (Some(&start), Some(&end)) => (start, end),
+            _ => return false,
+        };
+        if self.flat.data.drain(start..end).is_none() {
+            return false;
+        }
+        if self.flat.indices.drain(index + 1..index + 2).is_none() {
+            return false;
+        }
+        for offset in self.flat.indices.iter_mut().skip(index + 1) {
+            *offset -= end - start;
+        }
+        true
+    }
+
+    /// Removes the strings in `range`, returning them through an iterator, or returns `None` if
+    /// the range is out of bounds.
+    ///
+    /// The strings are removed when the iterator is dropped, whether or not they were all
+    /// consumed. If the iterator is leaked instead, the StrVec is left unchanged.
+    ///
+    /// ```
+    /// # use panicless::StrVec;
+    /// let mut queue: StrVec = ["a", "b", "c", "d"].iter().cloned().collect();
+    /// let taken: Vec<String> = queue.drain(..2).unwrap().collect();
+    /// assert_eq!(taken, ["a", "b"]);
+    /// assert_eq!(queue, ["c", "d"]);
+    /// assert!(queue.drain(1..3).is_none());
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Option<StrVecDrain<'_>> {
+        let range = checked_range(range, self.len())?;
+        Some(StrVecDrain {
+            strings: self,
+            front: range.start,
+            back: range.end,
+            start: range.start,
+            end: range.end,
+        })
+    }
+
+    /// Inserts `s` so that it is at `index`, shifting the strings after it up. Returns `false`
+    /// and leaves the StrVec alone if `index` is greater than the length.
+    pub fn insert(&mut self, index: usize, s: &str) -> bool {
+        let start = match self.flat.indices.get(index) {
+            Some(&start) => start,
+            None => return false,
+        };
+        if self.flat.data.splice(start..start, s.bytes()).is_none() {
+            return false;
+        }
+        for offset in self.flat.indices.iter_mut().skip(index + 1) {
+            *offset += s.len();
+        }
+        self.flat
+            .indices
+            .splice(index + 1..index + 1, iter::once(start + s.len()))
+            .is_some()
+    }
+
+    /// Swaps the strings at `a` and `b`. Returns `false` and leaves the StrVec alone if either
+    /// index is out of bounds.
+    ///
+    /// The bytes from the start of the first string to the end of the second are rotated in place
+    /// (by reversing the whole range, then each of the two strings and the part between them), so
+    /// this doesn't allocate, but it does move everything between the two strings.
+    pub fn swap(&mut self, a: usize, b: usize) -> bool {
+        if a >= self.len() || b >= self.len() {
+            return false;
+        }
+        let (first, second) = if a < b { (a, b) } else { (b, a) };
+        if first == second {
+            return true;
+        }
+
+        let (start, first_end, second_start, end) = unsafe {
+            (
+                *self.flat.indices.get_unchecked(first),
+                *self.flat.indices.get_unchecked(first + 1),
+                *self.flat.indices.get_unchecked(second),
+                *self.flat.indices.get_unchecked(second + 1),
+            )
+        };
+        let first_len = first_end - start;
+        let second_len = end - second_start;
+        let middle_len = second_start - first_end;
+
+        let region = match self.flat.data.get_mut(start..end) {
+            Some(region) => region,
+            None => return false,
+        };
+        region.reverse();
+        let (new_first, rest) = region.split_at_mut(second_len);
+        let (middle, new_second) = rest.split_at_mut(middle_len);
+        new_first.reverse();
+        middle.reverse();
+        new_second.reverse();
+
+        // Every boundary between the two strings moves by the difference in their lengths
+        let boundaries = self.flat.indices.get_mut(first + 1..=second);
+        for offset in boundaries.into_iter().flatten() {
+            *offset = *offset - first_len + second_len;
+        }
+        true
+    }
+
+    /// Pushes each whitespace-separated token of `text`, as split by `str::split_whitespace`.
+    pub fn extend_split_whitespace(&mut self, text: &str) {
+        // The tokens can't add up to more than the text
+        self.flat.data.reserve(text.len());
+        self.extend(text.split_whitespace());
+    }
+
+    /// Pushes each piece of `text` between occurrences of `delimiter`, as split by `str::split`.
+    /// Like `str::split`, this includes empty pieces between adjacent delimiters.
+    pub fn extend_split(&mut self, text: &str, delimiter: char) {
+        self.flat.data.reserve(text.len());
+        self.extend(text.split(delimiter));
+    }
+
+    /// Pushes each line of `text` as its own string, as split by `str::lines`, so the line
+    /// endings, `\n` or `\r\n`, are not included.
+    pub fn push_lines(&mut self, text: &str) {
+        self.flat.data.reserve(text.len());
+        self.extend(text.lines());
+    }
+
+    /// Iterates over the lines of the string at `index`, as split by `str::lines`, or returns
+    /// `None` if the index is out of bounds.
+    pub fn lines_of(&self, index: usize) -> Option<core::str::Lines<'_>> {
+        self.get(index).map(str::lines)
+    }
+
+    /// Formats `args` straight into the data buffer as a new string, without an intermediate
+    /// `String`. If formatting fails, the StrVec is left as it was and the error is returned.
+    ///
+    /// ```
+    /// # use panicless::StrVec;
+    /// let mut lines = StrVec::new();
+    /// lines.push_fmt(format_args!("{} + {} = {}", 1, 2, 1 + 2)).unwrap();
+    /// assert_eq!(lines.get(0), Some("1 + 2 = 3"));
+    /// ```
+    pub fn push_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
+        struct Appender<'a>(&'a mut Vec<u8>);
+
+        impl<'a> fmt::Write for Appender<'a> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.extend_from_slice(s.as_bytes());
+                Ok(())
+            }
+        }
+
+        let start = self.flat.data.len();
+        match fmt::write(&mut Appender(&mut self.flat.data), args) {
+            Ok(()) => {
+                self.flat.indices.push(self.flat.data.len());
+                Ok(())
+            }
+            Err(e) => {
+                // Whatever was written before the error isn't a string of ours
+                self.flat.data.truncate(start);
+                Err(e)
+            }
+        }
+    }
+
+    /// Keeps the first `len` strings and removes the rest, keeping the allocated capacity.
+    /// Does nothing if there are already no more than `len` strings.
+    pub fn truncate(&mut self, len: usize) {
+        self.flat.truncate(len);
+    }
+
+    /// Removes all the strings, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Sorts the strings. The sort is stable.
+    pub fn sort(&mut self) {
+        self.sort_by(|a, b| a.cmp(b))
+    }
+
+    /// Sorts the strings with a comparator function. The sort is stable.
+    ///
+    /// The strings can't be moved around inside the data buffer, so this sorts references to them
+    /// and then copies them into a new buffer in their sorted order.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&str, &str) -> Ordering,
+    {
+        let sorted = {
+            let mut strings: Vec<&str> = Vec::with_capacity(self.len());
+            strings.extend(self.iter());
+            strings.sort_by(|a, b| compare(a, b));
+
+            let mut sorted = StrVec::with_capacity(self.flat.data.len(), self.flat.indices.len());
+            for s in strings.iter() {
+                sorted.push(s);
+            }
+            sorted
+        };
+        *self = sorted;
+    }
+
+    /// Returns the indices of the strings in sorted order, without moving the strings. The sort
+    /// is stable, so equal strings keep their relative order.
+    ///
+    /// ```
+    /// # use panicless::StrVec;
+    /// let words: StrVec = ["pear", "apple", "fig"].iter().cloned().collect();
+    /// assert_eq!(words.sorted_indices()[..], [1, 2, 0]);
+    /// ```
+    pub fn sorted_indices(&self) -> Vec<usize> {
+        self.sorted_indices_by(|a, b| a.cmp(b))
+    }
+
+    /// Like `sorted_indices`, but with a comparison function.
+    pub fn sorted_indices_by<F>(&self, mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(&str, &str) -> Ordering,
+    {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        // Every index in order is below self.len()
+        order.sort_by(|&a, &b| unsafe { compare(self.get_unchecked(a), self.get_unchecked(b)) });
+        order
+    }
+
+    /// Removes consecutive repeated strings, compacting the data buffer in place.
+    pub fn dedup(&mut self) {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+
+        // The kept strings are the first `kept`, occupying data[..indices[kept]]. Indices are
+        // only overwritten at or below i, so the ones we're about to read are still the originals.
+        let mut kept = 1;
+        unsafe {
+            let data = self.flat.data.as_mut_ptr();
+            for i in 1..len {
+                let start = *self.flat.indices.get_unchecked(i);
+                let end = *self.flat.indices.get_unchecked(i + 1);
+                let prev_start = *self.flat.indices.get_unchecked(kept - 1);
+                let prev_end = *self.flat.indices.get_unchecked(kept);
+                if self.flat.data.get_unchecked(start..end)
+                    == self.flat.data.get_unchecked(prev_start..prev_end)
+                {
+                    continue;
+                }
+                ptr::copy(data.add(start), data.add(prev_end), end - start);
+                *self.flat.indices.get_unchecked_mut(kept + 1) = prev_end + (end - start);
+                kept += 1;
+            }
+        }
+
+        self.truncate(kept);
+    }
+
+    /// Keeps only the strings for which `f` returns `true`, compacting the data buffer in place.
+    pub fn retain<F: FnMut(&str) -> bool>(&mut self, mut f: F) {
+        let len = self.len();
+        // If f panics, the guard keeps only the strings already moved into place
+        let mut guard = RetainGuard {
+            strings: self,
+            kept: 0,
+        };
+
+        // Like dedup, the kept strings occupy data[..indices[kept]] and the indices that are
+        // read are never ones that have been overwritten.
+        unsafe {
+            let strings = &mut *guard.strings;
+            let data = strings.flat.data.as_mut_ptr();
+            for i in 0..len {
+                let start = *strings.flat.indices.get_unchecked(i);
+                let end = *strings.flat.indices.get_unchecked(i + 1);
+                let s = core::str::from_utf8_unchecked(strings.flat.data.get_unchecked(start..end));
+                if !f(s) {
+                    continue;
+                }
+                let kept_end = *strings.flat.indices.get_unchecked(guard.kept);
+                ptr::copy(data.add(start), data.add(kept_end), end - start);
+                *strings.flat.indices.get_unchecked_mut(guard.kept + 1) = kept_end + (end - start);
+                guard.kept += 1;
+            }
+        }
+    }
+
+    /// Sorts the strings and removes duplicates.
+    pub fn sort_and_dedup(&mut self) {
+        self.sort();
+        self.dedup();
+    }
+
+    /// Concatenates all the strings into one UTF-8 buffer, allocating exactly once.
+    pub fn concat(&self) -> Vec<u8> {
+        self.flat.data.clone()
+    }
+
+    /// Concatenates all the strings into one UTF-8 buffer with `separator` between each of them,
+    /// allocating exactly once.
+    pub fn join(&self, separator: &str) -> Vec<u8> {
+        // Saturating is as good as checking here, with_capacity aborts on anything that large
+        let separators = separator.len().saturating_mul(self.len().saturating_sub(1));
+        let mut joined = Vec::with_capacity(self.flat.data.len().saturating_add(separators));
+        for (i, s) in self.iter().enumerate() {
+            if i > 0 {
+                joined.extend_from_slice(separator.as_bytes());
+            }
+            joined.extend_from_slice(s.as_bytes());
+        }
+        joined
+    }
+
+    pub fn iter(&self) -> StrVecIter<'_> {
+        self.as_slice().iter()
+    }
+
+    /// Copies every string into its own `String`, for APIs that want owned strings.
+    pub fn to_vec(&self) -> StdVec<String> {
+        self.iter().map(String::from).collect()
+    }
+
+    /// Borrows the strings in `range` as a `StrVecSlice`, or returns `None` if the range is out
+    /// of bounds.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Option<StrVecSlice<'_>> {
+        self.as_slice().slice(range)
+    }
+
+    fn as_slice(&self) -> StrVecSlice<'_> {
+        StrVecSlice {
+            data: &self.flat.data,
+            indices: &self.flat.indices,
         }
     }
 
     pub fn len(&self) -> usize {
-        self.indices.len() - 1
+        self.flat.len()
+    }
+}
+
+pub struct StrVecDrain<'a> {
+    strings: &'a mut StrVec,
+    // The not-yet-yielded strings are those in front..back
+    front: usize,
+    back: usize,
+    // The strings in start..end are removed on drop
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for StrVecDrain<'a> {
+    type Item = String;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            self.strings.get(self.front - 1).map(String::from)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrVecDrain<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            self.strings.get(self.back).map(String::from)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for StrVecDrain<'a> {}
+
+impl<'a> FusedIterator for StrVecDrain<'a> {}
+
+impl<'a> Drop for StrVecDrain<'a> {
+    fn drop(&mut self) {
+        let strings = &mut *self.strings;
+        let (byte_start, byte_end) = match (
+            strings.flat.indices.get(self.start),
+            strings.flat.indices.get(self.end),
+        ) {
+            (Some(&start), Some(&end)) => (start, end),
+            _ => return,
+        };
+        // Both ranges were checked when the drain was created
+        drop(strings.flat.data.drain(byte_start..byte_end));
+        drop(strings.flat.indices.drain(self.start + 1..self.end + 1));
+        for offset in strings.flat.indices.iter_mut().skip(self.start + 1) {
+            *offset -= byte_end - byte_start;
+        }
+    }
+}
+
+struct RetainGuard<'a> {
+    strings: &'a mut StrVec,
+    kept: usize,
+}
+
+impl<'a> Drop for RetainGuard<'a> {
+    fn drop(&mut self) {
+        self.strings.truncate(self.kept);
+    }
+}
+
+#[cfg(feature = "std")]
+mod io_impls {
+    use super::StrVec;
+    use core::convert::TryFrom;
+    use core::mem::size_of;
+    use std::io::{self, BufRead, Read, Write};
+
+    impl StrVec {
+        /// Reads `reader` to the end, storing each line as a string. Lines are split like
+        /// `BufRead::lines`: the `\n` or `\r\n` is removed, and invalid UTF-8 is an error.
+        pub fn from_lines<R: BufRead>(reader: R) -> io::Result<StrVec> {
+            let mut lines = StrVec::new();
+            lines.extend_lines(reader)?;
+            Ok(lines)
+        }
+
+        /// Like `from_lines`, but appends to an existing StrVec. On error, the lines read
+        /// before the failing one are kept.
+        pub fn extend_lines<R: BufRead>(&mut self, mut reader: R) -> io::Result<()> {
+            while self.push_line(&mut reader)? {}
+            Ok(())
+        }
+
+        /// Writes every string to `writer` with `separator` between each of them, straight from
+        /// the data buffer without allocating.
+        pub fn write_joined<W: Write>(&self, mut writer: W, separator: &[u8]) -> io::Result<()> {
+            for (i, s) in self.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(separator)?;
+                }
+                writer.write_all(s.as_bytes())?;
+            }
+            Ok(())
+        }
+
+        /// Writes the StrVec to `writer` in a compact binary format that `read_from` can load.
+        ///
+        /// The format is the number of strings, then the byte offset where each one ends, all as
+        /// little-endian u64s, followed by the string data itself.
+        pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+            writer.write_all(&(self.len() as u64).to_le_bytes())?;
+            for &end in self.flat.indices.iter().skip(1) {
+                writer.write_all(&(end as u64).to_le_bytes())?;
+            }
+            writer.write_all(&self.flat.data)
+        }
+
+        /// Reads a StrVec written by `write_to`. Input that isn't in that format, including
+        /// offsets that go backwards or split a UTF-8 character, is an `InvalidData` error.
+        pub fn read_from<R: Read>(mut reader: R) -> io::Result<StrVec> {
+            fn invalid(message: &str) -> io::Error {
+                io::Error::new(io::ErrorKind::InvalidData, message)
+            }
+
+            fn read_u64<R: Read>(reader: &mut R) -> io::Result<usize> {
+                let mut bytes = [0; 8];
+                reader.read_exact(&mut bytes)?;
+                usize::try_from(u64::from_le_bytes(bytes))
+                    .map_err(|_| invalid("StrVec offset doesn't fit in usize"))
+            }
+
+            let len = read_u64(&mut reader)?;
+            // The count comes from the input, so don't let it talk us into a huge allocation
+            const MAX_PREALLOC_STRINGS: usize = 1024 * 1024 / size_of::<usize>();
+            let mut strings = StrVec::with_capacity(0, len.min(MAX_PREALLOC_STRINGS) + 1);
+            for _ in 0..len {
+                let end = read_u64(&mut reader)?;
+                if strings
+                    .flat
+                    .indices
+                    .last()
+                    .is_some_and(|&start| end < start)
+                {
+                    return Err(invalid("StrVec offsets are out of order"));
+                }
+                strings.flat.indices.push(end);
+            }
+
+            // Copied through a Take instead of reserved up front for the same reason as above
+            let data_len = strings.flat.indices.last().map_or(0, |&end| end) as u64;
+            let copied = io::copy(&mut reader.take(data_len), &mut strings.flat.data)?;
+            if copied != data_len {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+
+            let text = core::str::from_utf8(&strings.flat.data)
+                .map_err(|_| invalid("StrVec data is not valid UTF-8"))?;
+            if !strings
+                .flat
+                .indices
+                .iter()
+                .all(|&i| text.is_char_boundary(i))
+            {
+                return Err(invalid("StrVec offset is not on a char boundary"));
+            }
+            Ok(strings)
+        }
+
+        /// Reads one line straight into the data buffer and pushes it, returning `false` if the
+        /// reader was already at its end
+        fn push_line<R: BufRead>(&mut self, reader: &mut R) -> io::Result<bool> {
+            let start = self.flat.data.len();
+            let mut read_any = false;
+            let mut newline = false;
+            while !newline {
+                let available = match reader.fill_buf() {
+                    Ok(available) => available,
+                    Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        self.flat.data.truncate(start);
+                        return Err(e);
+                    }
+                };
+                if available.is_empty() {
+                    break;
+                }
+                read_any = true;
+                let used = match available.iter().position(|&b| b == b'\n') {
+                    Some(i) => {
+                        newline = true;
+                        self.flat.data.extend_from_slice(&available[..i]);
+                        i + 1
+                    }
+                    None => {
+                        self.flat.data.extend_from_slice(available);
+                        available.len()
+                    }
+                };
+                reader.consume(used);
+            }
+
+            if newline && self.flat.data.len() > start && self.flat.data.last() == Some(&b'\r') {
+                self.flat.data.truncate(self.flat.data.len() - 1);
+            }
+            if core::str::from_utf8(&self.flat.data[start..]).is_err() {
+                self.flat.data.truncate(start);
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                ));
+            }
+            if read_any {
+                self.flat.indices.push(self.flat.data.len());
+            }
+            Ok(read_any)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::StrVec;
+    use core::fmt;
+    use core::mem::size_of;
+    use serde::de::{Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    impl Serialize for StrVec {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    // Deserializes one string straight into the end of a StrVec, so strings that the input can
+    // lend us get copied exactly once, and no others need a temporary String either
+    struct PushStr<'a>(&'a mut StrVec);
+
+    impl<'de, 'a> DeserializeSeed<'de> for PushStr<'a> {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'de, 'a> Visitor<'de> for PushStr<'a> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<(), E> {
+            self.0.push(s);
+            Ok(())
+        }
+    }
+
+    struct StrVecVisitor;
+
+    impl<'de> Visitor<'de> for StrVecVisitor {
+        type Value = StrVec;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence of strings")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<StrVec, A::Error> {
+            // The size hint comes from the input, so don't let it talk us into a huge allocation
+            const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+            let max_strings = MAX_PREALLOC_BYTES / size_of::<usize>();
+            let strings = seq.size_hint().unwrap_or(0).min(max_strings);
+
+            let mut vec = StrVec::new();
+            vec.reserve(0, strings);
+            while seq.next_element_seed(PushStr(&mut vec))?.is_some() {}
+            Ok(vec)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for StrVec {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(StrVecVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon_impls {
+    use super::StrVec;
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+    use rayon::slice::ParallelSlice;
+
+    impl StrVec {
+        /// Returns a parallel iterator over the strings. Each string is a window of two adjacent
+        /// offsets into the data buffer, so this splits the offset table between rayon jobs.
+        pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &str> + '_ {
+            let data = &self.flat.data;
+            self.flat.indices.par_windows(2).map(move |bounds| unsafe {
+                // The indices are always in bounds and on char boundaries
+                let bytes = data.get_unchecked(bounds[0]..bounds[1]);
+                core::str::from_utf8_unchecked(bytes)
+            })
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use super::StrVec;
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    impl<'a> Arbitrary<'a> for StrVec {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let mut strings = StrVec::new();
+            for s in u.arbitrary_iter::<&str>()? {
+                strings.push(s?);
+            }
+            Ok(strings)
+        }
+
+        fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+            let mut strings = StrVec::new();
+            for s in u.arbitrary_take_rest_iter::<&str>()? {
+                strings.push(s?);
+            }
+            Ok(strings)
+        }
     }
 }
 
@@ -102,8 +1213,8 @@ mod tests {
     #[test]
     fn creation_assumptions() {
         let words = StrVec::new();
-        assert_eq!(words.indices.len(), 1);
-        assert_eq!(words.indices.get(0), Some(&0));
+        assert_eq!(words.flat.indices.len(), 1);
+        assert_eq!(words.flat.indices.first(), Some(&0));
 
         let iter = words.iter();
         assert_eq!(iter.index, 0);
@@ -138,4 +1249,653 @@ mod tests {
         assert_eq!(iter.next(), Some("abc"));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn try_reserve() {
+        let mut words = StrVec::with_capacity(0, 0);
+        words.try_reserve(100, 10).unwrap();
+        assert!(words.flat.data.capacity() >= 100);
+        assert!(words.flat.indices.capacity() >= 11);
+
+        words.push("abc");
+        assert_eq!(words.try_reserve(usize::MAX, 0), Err(AllocError));
+        assert_eq!(words.try_reserve(0, usize::MAX), Err(AllocError));
+        assert_eq!(words.get(0), Some("abc"));
+    }
+
+    #[test]
+    fn heap_size() {
+        let words = StrVec::with_capacity(100, 10);
+        assert_eq!(words.heap_size(), 100 + 10 * core::mem::size_of::<usize>());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes = [1, 1, b'a', 1, 2, b'b', b'c', 0];
+        let mut u = Unstructured::new(&bytes);
+        let words = StrVec::arbitrary(&mut u).unwrap();
+        assert_eq!(words, ["", "a\u{1}\u{2}b"]);
+        // The same strings a Vec<&str> decodes from those bytes
+        let mut u = Unstructured::new(&bytes);
+        assert_eq!(words, StdVec::<&str>::arbitrary(&mut u).unwrap()[..]);
+
+        let mut u = Unstructured::new(&[]);
+        assert!(StrVec::arbitrary(&mut u).unwrap().is_empty());
+    }
+
+    #[test]
+    fn truncate_clear() {
+        let mut words = StrVec::new();
+        words.push("a");
+        words.push("bc");
+        words.push("def");
+        words.truncate(5);
+        assert_eq!(words.len(), 3);
+
+        words.truncate(2);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words.get(1), Some("bc"));
+        assert_eq!(words.get(2), None);
+        assert_eq!(words.flat.data.len(), 3);
+
+        let capacity = words.flat.data.capacity();
+        words.clear();
+        assert!(words.is_empty());
+        assert_eq!(words.flat.indices[..], [0]);
+        assert_eq!(words.flat.data.capacity(), capacity);
+
+        words.push("xyz");
+        assert_eq!(words.get(0), Some("xyz"));
+    }
+
+    #[test]
+    fn first_last() {
+        let mut words = StrVec::new();
+        assert_eq!(words.first(), None);
+        assert_eq!(words.last(), None);
+        words.push("a");
+        assert_eq!(words.first(), Some("a"));
+        assert_eq!(words.last(), Some("a"));
+        words.push("b");
+        assert_eq!(words.first(), Some("a"));
+        assert_eq!(words.last(), Some("b"));
+    }
+
+    #[test]
+    fn extend_collect() {
+        let mut words: StrVec = "a few words".split(' ').collect();
+        assert_eq!(words.len(), 3);
+        words.extend([String::from("and"), String::from("more")]);
+        words.extend(["!"; 2]);
+        let all: Vec<&str> = words.iter().collect();
+        assert_eq!(all[..], ["a", "few", "words", "and", "more", "!", "!"]);
+
+        let owned: StrVec = ["x", "y"].iter().map(|s| String::from(*s)).collect();
+        assert_eq!(owned.get(1), Some("y"));
+    }
+
+    #[test]
+    fn reserve() {
+        let mut words = StrVec::with_capacity(0, 1);
+        assert_eq!(words.data_capacity(), 0);
+        assert_eq!(words.index_capacity(), 0);
+
+        words.reserve(10, 3);
+        assert!(words.data_capacity() >= 10);
+        assert!(words.index_capacity() >= 3);
+
+        words.push("abc");
+        words.reserve(0, 0);
+        assert!(words.index_capacity() >= 1);
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut words = StrVec::with_capacity(100, 10);
+        words.push("abc");
+        words.shrink_to_fit();
+        assert_eq!(words.data_capacity(), 3);
+        assert_eq!(words.index_capacity(), 1);
+        assert_eq!(words.get(0), Some("abc"));
+
+        let mut empty = StrVec::new();
+        empty.shrink_to_fit();
+        assert_eq!(empty.data_capacity(), 0);
+        empty.push("x");
+        assert_eq!(empty.last(), Some("x"));
+    }
+
+    #[test]
+    fn join_concat() {
+        let mut words = StrVec::new();
+        assert!(words.concat().is_empty());
+        assert!(words.join(", ").is_empty());
+
+        words.push("a");
+        assert_eq!(words.join(", "), *b"a");
+        words.push("bc");
+        words.push("");
+        assert_eq!(words.concat(), *b"abc");
+
+        let joined = words.join(", ");
+        assert_eq!(joined, *b"a, bc, ");
+        assert_eq!(joined.capacity(), joined.len());
+        assert_eq!(words.join(""), words.concat());
+    }
+
+    #[test]
+    fn contains_position() {
+        let words: StrVec = ["a", "b", "", "b"].iter().cloned().collect();
+        assert_eq!(words.position("b"), Some(1));
+        assert_eq!(words.position(""), Some(2));
+        assert_eq!(words.position("ab"), None);
+        assert!(words.contains("a"));
+        assert!(!words.contains("c"));
+        assert!(!StrVec::new().contains(""));
+    }
+
+    #[test]
+    fn binary_search() {
+        let words: StrVec = ["apple", "banana", "cherry", "date"]
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(words.binary_search("apple"), Ok(0));
+        assert_eq!(words.binary_search("date"), Ok(3));
+        assert_eq!(words.binary_search("a"), Err(0));
+        assert_eq!(words.binary_search("blueberry"), Err(2));
+        assert_eq!(words.binary_search("zucchini"), Err(4));
+        assert_eq!(StrVec::new().binary_search("x"), Err(0));
+    }
+
+    #[test]
+    fn sort() {
+        let mut words: StrVec = ["pear", "fig", "apple", "fig", ""]
+            .iter()
+            .cloned()
+            .collect();
+        words.sort();
+        assert_eq!(
+            words.iter().collect::<Vec<_>>(),
+            ["", "apple", "fig", "fig", "pear"]
+        );
+        assert!(words.binary_search("fig").is_ok());
+
+        words.sort_by(|a, b| b.len().cmp(&a.len()));
+        assert_eq!(
+            words.iter().collect::<Vec<_>>(),
+            ["apple", "pear", "fig", "fig", ""]
+        );
+
+        let mut empty = StrVec::new();
+        empty.sort();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn dedup() {
+        let mut words: StrVec = ["a", "a", "bb", "a", "", "", "cc", "cc"]
+            .iter()
+            .cloned()
+            .collect();
+        words.dedup();
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["a", "bb", "a", "", "cc"]);
+        assert_eq!(words.flat.data.len(), 6);
+        assert_eq!(words.flat.indices.len(), 6);
+
+        words.sort_and_dedup();
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["", "a", "bb", "cc"]);
+
+        let mut one: StrVec = ["x"].iter().cloned().collect();
+        one.dedup();
+        assert_eq!(one.first(), Some("x"));
+    }
+
+    #[test]
+    fn replace() {
+        let mut words: StrVec = ["a", "bb", "ccc"].iter().cloned().collect();
+        assert!(words.replace(1, "longer"));
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["a", "longer", "ccc"]);
+        assert!(words.replace(0, ""));
+        assert!(words.replace(2, "c"));
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["", "longer", "c"]);
+        assert_eq!(words.flat.data.len(), 7);
+
+        assert!(!words.replace(3, "nope"));
+        assert_eq!(words.len(), 3);
+    }
+
+    #[test]
+    fn remove_insert() {
+        let mut words: StrVec = ["a", "bb", "ccc"].iter().cloned().collect();
+        assert!(words.remove(1));
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["a", "ccc"]);
+        assert!(!words.remove(2));
+
+        assert!(words.insert(1, "dddd"));
+        assert!(words.insert(0, ""));
+        assert!(words.insert(4, "e"));
+        assert_eq!(
+            words.iter().collect::<Vec<_>>(),
+            ["", "a", "dddd", "ccc", "e"]
+        );
+        assert!(!words.insert(6, "f"));
+        assert_eq!(words.len(), 5);
+
+        while words.remove(0) {}
+        assert!(words.is_empty());
+        assert_eq!(words.flat.indices[..], [0]);
+        assert!(words.flat.data.is_empty());
+    }
+
+    #[test]
+    fn swap() {
+        let mut words: StrVec = ["a", "bb", "", "dddd"].iter().cloned().collect();
+        assert!(words.swap(0, 3));
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["dddd", "bb", "", "a"]);
+        assert!(words.swap(2, 1));
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["dddd", "", "bb", "a"]);
+        assert!(words.swap(2, 2));
+        assert!(words.swap(0, 1));
+        assert_eq!(words.iter().collect::<Vec<_>>(), ["", "dddd", "bb", "a"]);
+
+        assert!(!words.swap(0, 4));
+        assert!(!words.swap(4, 4));
+        assert_eq!(words.len(), 4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let words: StrVec = ["a", "b\"c", ""].iter().cloned().collect();
+        let json = serde_json::to_string(&words).unwrap();
+        assert_eq!(json, r#"["a","b\"c",""]"#);
+
+        let back: StrVec = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.iter().collect::<Vec<_>>(), ["a", "b\"c", ""]);
+        let from_reader: StrVec = serde_json::from_reader(json.as_bytes()).unwrap();
+        assert_eq!(from_reader.len(), 3);
+
+        assert!(serde_json::from_str::<StrVec>("[]").unwrap().is_empty());
+        assert!(serde_json::from_str::<StrVec>("[1]").is_err());
+    }
+
+    #[test]
+    fn compare_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::string::ToString;
+
+        fn hash(words: &StrVec) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            words.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let ab: StrVec = ["a", "b"].iter().cloned().collect();
+        let a_b: StrVec = ["ab", ""].iter().cloned().collect();
+        assert_eq!(ab, ["a", "b"]);
+        assert_eq!(ab, &["a", "b"][..]);
+        assert_eq!(ab, std::vec!["a".to_string(), "b".to_string()]);
+        assert_ne!(ab, a_b);
+        assert_ne!(ab, ["a"]);
+        assert_ne!(hash(&ab), hash(&a_b));
+
+        let mut again = StrVec::with_capacity(0, 0);
+        again.push("a");
+        again.push("b");
+        assert_eq!(ab, again);
+        assert_eq!(hash(&ab), hash(&again));
+
+        assert!(ab < a_b);
+        assert!(StrVec::new() < ab);
+        assert_eq!(ab.cmp(&again), Ordering::Equal);
+    }
+
+    #[test]
+    fn debug_clone() {
+        let words: StrVec = ["a", "b\"c"].iter().cloned().collect();
+        assert_eq!(std::format!("{:?}", words), r#"["a", "b\"c"]"#);
+        assert_eq!(std::format!("{:?}", StrVec::new()), "[]");
+
+        let mut copy = words.clone();
+        assert_eq!(copy, words);
+        copy.push("d");
+        assert_eq!(words.len(), 2);
+        assert_eq!(copy, ["a", "b\"c", "d"]);
+    }
+
+    #[test]
+    fn iterate_both_ends() {
+        let words: StrVec = ["a", "b", "c", "d"].iter().cloned().collect();
+        let mut iter = words.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some("a"));
+        assert_eq!(iter.next_back(), Some("d"));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next_back(), Some("c"));
+        assert_eq!(iter.next(), Some("b"));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(words.iter().rev().collect::<Vec<_>>(), ["d", "c", "b", "a"]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter() {
+        use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+        let words: StrVec = (0..1000u32).map(|i| std::format!("{}", i)).collect();
+        assert_eq!(words.par_iter().len(), 1000);
+        let total: usize = words.par_iter().map(str::len).sum();
+        assert_eq!(total, words.concat().len());
+        let found = words.par_iter().position_any(|s| s == "567");
+        assert_eq!(found, Some(567));
+        assert_eq!(StrVec::new().par_iter().count(), 0);
+    }
+
+    #[test]
+    fn slice() {
+        let words: StrVec = ["a", "bb", "ccc", "dddd"].iter().cloned().collect();
+        let middle = words.slice(1..3).unwrap();
+        assert_eq!(middle.len(), 2);
+        assert_eq!(middle.get(0), Some("bb"));
+        assert_eq!(middle.get(1), Some("ccc"));
+        assert_eq!(middle.get(2), None);
+        assert_eq!(middle.iter().rev().collect::<Vec<_>>(), ["ccc", "bb"]);
+        assert_eq!(std::format!("{:?}", middle), r#"["bb", "ccc"]"#);
+
+        let last = middle.slice(1..).unwrap();
+        assert_eq!(last.iter().collect::<Vec<_>>(), ["ccc"]);
+        assert!(middle.slice(..3).is_none());
+
+        assert_eq!(words.slice(..).unwrap().len(), 4);
+        assert!(words.slice(4..).unwrap().is_empty());
+        assert!(words.slice(2..5).is_none());
+    }
+
+    #[test]
+    fn push_fmt() {
+        struct Fails;
+        impl fmt::Display for Fails {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("partial")?;
+                Err(fmt::Error)
+            }
+        }
+
+        let mut words = StrVec::new();
+        words.push("a");
+        words.push_fmt(format_args!("{}-{:02}", "b", 7)).unwrap();
+        assert!(words.push_fmt(format_args!("x{}", Fails)).is_err());
+        words.push_fmt(format_args!("")).unwrap();
+        assert_eq!(words, ["a", "b-07", ""]);
+        assert_eq!(words.flat.data.len(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_lines() {
+        let text = "first\nsecond\r\n\nlast\r";
+        let lines = StrVec::from_lines(text.as_bytes()).unwrap();
+        assert_eq!(lines, ["first", "second", "", "last\r"]);
+
+        // A tiny buffer, so lines arrive in pieces
+        let mut lines = StrVec::new();
+        let reader = std::io::BufReader::with_capacity(2, "abcde\nfg\n".as_bytes());
+        lines.extend_lines(reader).unwrap();
+        assert_eq!(lines, ["abcde", "fg"]);
+
+        assert!(StrVec::from_lines(&b""[..]).unwrap().is_empty());
+
+        let mut lines = StrVec::new();
+        let err = lines.extend_lines(&b"ok\n\xff\nafter\n"[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(lines, ["ok"]);
+    }
+
+    #[test]
+    fn extend_split() {
+        let mut words = StrVec::new();
+        words.extend_split_whitespace("  the quick\tbrown\n fox ");
+        assert_eq!(words, ["the", "quick", "brown", "fox"]);
+        words.extend_split_whitespace("   ");
+        assert_eq!(words.len(), 4);
+
+        let mut fields = StrVec::new();
+        fields.extend_split("a,b,,c", ',');
+        assert_eq!(fields, ["a", "b", "", "c"]);
+        fields.extend_split("naïve→café", '→');
+        assert_eq!(fields.last(), Some("café"));
+    }
+
+    #[test]
+    fn find_all() {
+        let words: StrVec = ["a", "aa", "", "xaax", "a"].iter().cloned().collect();
+        // The match at the very start spans "a" and "aa", but the one inside "aa" must be found
+        let found: Vec<_> = words.find_all("aa").collect();
+        assert_eq!(found, [(1, 0), (3, 1)]);
+        let found: Vec<_> = words.find_all("a").collect();
+        assert_eq!(found, [(0, 0), (1, 0), (1, 1), (3, 1), (3, 2), (4, 0)]);
+        assert_eq!(words.find_all("xa").count(), 1);
+        assert_eq!(words.find_all("aaa").count(), 0);
+        assert_eq!(words.find_all("").count(), 0);
+        assert_eq!(StrVec::new().find_all("a").count(), 0);
+    }
+
+    #[test]
+    fn iter_with_prefix() {
+        let mut words: StrVec = ["cart", "car", "cat", "ca", "dog", "carp", "c"]
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(
+            words.iter_with_prefix("car").collect::<Vec<_>>(),
+            ["cart", "car", "carp"]
+        );
+
+        words.sort();
+        let sorted: Vec<_> = words.sorted_iter_with_prefix("car").collect();
+        assert_eq!(sorted, ["car", "carp", "cart"]);
+        assert_eq!(words.sorted_iter_with_prefix("").len(), words.len());
+        assert_eq!(
+            words.sorted_iter_with_prefix("d").collect::<Vec<_>>(),
+            ["dog"]
+        );
+        assert_eq!(words.sorted_iter_with_prefix("e").len(), 0);
+        assert_eq!(words.sorted_iter_with_prefix("b").len(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_read() {
+        let words: StrVec = ["a", "", "naïve"].iter().cloned().collect();
+        let mut bytes = Vec::new();
+        words.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 8 * 4 + 7);
+        assert_eq!(StrVec::read_from(&bytes[..]).unwrap(), words);
+
+        let mut empty = Vec::new();
+        StrVec::new().write_to(&mut empty).unwrap();
+        assert!(StrVec::read_from(&empty[..]).unwrap().is_empty());
+
+        // Truncated data
+        assert!(StrVec::read_from(&bytes[..bytes.len() - 1]).is_err());
+        // Splits the ï in two
+        let mut split = bytes.clone();
+        split[8..16].copy_from_slice(&4u64.to_le_bytes());
+        split[16..24].copy_from_slice(&4u64.to_le_bytes());
+        assert_eq!(
+            StrVec::read_from(&split[..]).unwrap_err().kind(),
+            std::io::ErrorKind::InvalidData
+        );
+        // Offsets going backwards
+        let mut backwards = bytes.clone();
+        backwards[16..24].copy_from_slice(&0u64.to_le_bytes());
+        assert!(StrVec::read_from(&backwards[..]).is_err());
+    }
+
+    #[test]
+    fn spans() {
+        let strings: StrVec = ["a", "", "bcd"].iter().copied().collect();
+        assert_eq!(strings.total_bytes(), 4);
+        assert!(strings.lengths().eq([1, 0, 3].iter().copied()));
+        assert!(strings.spans().eq([(0, 1), (1, 1), (1, 4)].iter().copied()));
+        assert_eq!(strings.spans().len(), 3);
+        assert_eq!(strings.lengths().next_back(), Some(3));
+        assert_eq!(StrVec::new().spans().next(), None);
+    }
+
+    #[test]
+    fn get_unchecked() {
+        let strings: StrVec = ["a", "", "bcd"].iter().copied().collect();
+        for (i, s) in strings.iter().enumerate() {
+            assert_eq!(unsafe { strings.get_unchecked(i) }, s);
+        }
+    }
+
+    #[test]
+    fn ignore_ascii_case() {
+        let strings: StrVec = ["Hello", "WORLD", "straße"].iter().copied().collect();
+        assert_eq!(strings.position_ignore_ascii_case("hello"), Some(0));
+        assert_eq!(strings.position_ignore_ascii_case("World"), Some(1));
+        assert_eq!(strings.position_ignore_ascii_case("STRAßE"), Some(2));
+        assert!(!strings.contains_ignore_ascii_case("STRASSE"));
+        assert!(!strings.contains_ignore_ascii_case("hell"));
+        assert!(strings.contains_ignore_ascii_case("wOrLd"));
+    }
+
+    #[test]
+    fn retain() {
+        let mut strings: StrVec = ["apple", "", "banana", "avocado", "cherry"]
+            .iter()
+            .copied()
+            .collect();
+        strings.retain(|s| !s.starts_with('a'));
+        assert_eq!(strings, ["", "banana", "cherry"]);
+        assert_eq!(strings.total_bytes(), 12);
+        strings.retain(|_| false);
+        assert!(strings.is_empty());
+        assert_eq!(strings.total_bytes(), 0);
+    }
+
+    #[test]
+    fn retain_panic() {
+        let mut strings: StrVec = ["a", "b", "c", "d"].iter().copied().collect();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            strings.retain(|s| match s {
+                "b" => false,
+                "d" => panic!(),
+                _ => true,
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(strings, ["a", "c"]);
+    }
+
+    #[test]
+    fn drain() {
+        let mut strings: StrVec = ["a", "bb", "", "ccc", "d"].iter().copied().collect();
+        {
+            let mut drain = strings.drain(1..4).unwrap();
+            assert_eq!(drain.len(), 3);
+            assert_eq!(drain.next_back(), Some(String::from("ccc")));
+            assert_eq!(drain.next(), Some(String::from("bb")));
+        }
+        assert_eq!(strings, ["a", "d"]);
+        assert_eq!(strings.total_bytes(), 2);
+
+        assert_eq!(strings.drain(2..).unwrap().count(), 0);
+        assert!(strings.drain(..3).is_none());
+        assert_eq!(strings.drain(..).unwrap().count(), 2);
+        assert!(strings.is_empty());
+        strings.push("e");
+        assert_eq!(strings, ["e"]);
+    }
+
+    #[test]
+    fn std_conversions() {
+        let owned = std::vec![String::from("a"), String::new(), String::from("bc")];
+        let strings = StrVec::from(owned.clone());
+        assert_eq!(strings, owned);
+        assert_eq!(strings.to_vec(), owned);
+        assert_eq!(strings.data_capacity(), 3);
+        assert_eq!(strings.index_capacity(), 3);
+
+        let borrowed: &[&str] = &["x", "yz"];
+        assert_eq!(StrVec::from(borrowed), borrowed);
+        assert!(StrVec::new().to_vec().is_empty());
+    }
+
+    #[test]
+    fn iter_spans() {
+        let strings: StrVec = ["ab", "", "cde"].iter().copied().collect();
+        let concat = strings.concat();
+        for (i, range, s) in strings.iter_spans() {
+            assert_eq!(strings.get(i), Some(s));
+            assert_eq!(&concat[range], s.as_bytes());
+        }
+        assert!(strings
+            .iter_spans()
+            .rev()
+            .map(|(i, range, _)| (i, range))
+            .eq([(2, 2..5), (1, 2..2), (0, 0..2)]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_joined() {
+        let words: StrVec = ["a", "", "bc"].iter().cloned().collect();
+        let mut out = Vec::new();
+        words.write_joined(&mut out, b"\r\n").unwrap();
+        assert_eq!(out[..], b"a\r\n\r\nbc"[..]);
+        assert_eq!(out, words.join("\r\n"));
+
+        out.clear();
+        StrVec::new().write_joined(&mut out, b",").unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn lines() {
+        let mut log = StrVec::new();
+        log.push_lines("first\r\nsecond\n\nthird\n");
+        assert_eq!(log, ["first", "second", "", "third"]);
+
+        log.push("multi\nline\r\nentry");
+        assert!(log.lines_of(4).unwrap().eq(["multi", "line", "entry"]));
+        assert_eq!(log.lines_of(2).unwrap().count(), 0);
+        assert!(log.lines_of(5).is_none());
+    }
+
+    #[test]
+    fn sorted_indices() {
+        let words: StrVec = ["b", "A", "a", "c", "B"].iter().copied().collect();
+        assert_eq!(words.sorted_indices()[..], [1, 4, 2, 0, 3]);
+        let ignore_case =
+            words.sorted_indices_by(|a, b| a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()));
+        assert_eq!(ignore_case[..], [1, 2, 0, 4, 3]);
+        assert!(StrVec::new().sorted_indices().is_empty());
+    }
+
+    #[test]
+    fn append() {
+        let mut a: StrVec = ["x", ""].iter().copied().collect();
+        let mut b: StrVec = ["yz", "w"].iter().copied().collect();
+        a.append(&mut b);
+        assert_eq!(a, ["x", "", "yz", "w"]);
+        assert!(b.is_empty());
+        assert_eq!(b.total_bytes(), 0);
+
+        a.append(&mut b);
+        assert_eq!(a.len(), 4);
+        b.append(&mut a);
+        assert_eq!(b, ["x", "", "yz", "w"]);
+        assert!(a.is_empty());
+    }
 }
@@ -0,0 +1,180 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+/// A read-only StrVec over a buffer it doesn't own, such as a memory-mapped file.
+///
+/// The buffer must be in the format written by `StrVec::write_to`. It is validated once, when the
+/// view is created, and after that lookups read the offsets straight out of the buffer.
+#[derive(Clone, Copy)]
+pub struct StrVecView<'a> {
+    // The end offset of each string, as unaligned little-endian u64s
+    offsets: &'a [u8],
+    data: &'a str,
+}
+
+impl<'a> StrVecView<'a> {
+    /// Validates `bytes` and wraps them in a view, or returns `None` if they aren't in the
+    /// `StrVec::write_to` format. That includes offsets that go backwards or split a UTF-8
+    /// character, and trailing bytes after the string data.
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// # use panicless::{StrVec, StrVecView};
+    /// let words: StrVec = ["hello", "world"].iter().cloned().collect();
+    /// let mut bytes = Vec::new();
+    /// words.write_to(&mut bytes).unwrap();
+    ///
+    /// let view = StrVecView::new(&bytes).unwrap();
+    /// assert_eq!(view.get(1), Some("world"));
+    /// # }
+    /// ```
+    pub fn new(bytes: &'a [u8]) -> Option<Self> {
+        let (len, rest) = read_offset(bytes)?;
+        let offsets_len = len.checked_mul(8)?;
+        if rest.len() < offsets_len {
+            return None;
+        }
+        let (offsets, data) = rest.split_at(offsets_len);
+        let data = core::str::from_utf8(data).ok()?;
+
+        let mut start = 0;
+        for chunk in offsets.chunks_exact(8) {
+            // An offset too large for a usize can't be in bounds either
+            let end = to_offset(chunk)?;
+            if end < start || !data.is_char_boundary(end) {
+                return None;
+            }
+            start = end;
+        }
+        if start != data.len() {
+            return None;
+        }
+
+        Some(StrVecView { offsets, data })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len() / 8
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a str> {
+        let (start, end) = self.span(index)?;
+        self.data.get(start..end)
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a str> + ExactSizeIterator + 'a {
+        let view = *self;
+        // Every index is in bounds and new checked the offsets, so get never fails here
+        (0..self.len()).map(move |i| view.get(i).unwrap_or_default())
+    }
+
+    /// The byte range of the string at `index`, or `None` if it is out of bounds
+    fn span(&self, index: usize) -> Option<(usize, usize)> {
+        let offset = |i: usize| {
+            let start = i.checked_mul(8)?;
+            to_offset(self.offsets.get(start..start.checked_add(8)?)?)
+        };
+        let start = if index == 0 { 0 } else { offset(index - 1)? };
+        Some((start, offset(index)?))
+    }
+}
+
+impl<'a> fmt::Debug for StrVecView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Splits a little-endian u64 that fits in a usize off the front of `bytes`
+fn read_offset(bytes: &[u8]) -> Option<(usize, &[u8])> {
+    if bytes.len() < 8 {
+        return None;
+    }
+    let (offset, rest) = bytes.split_at(8);
+    Some((to_offset(offset)?, rest))
+}
+
+/// Converts 8 little-endian bytes to a usize, or `None` if they aren't 8 or don't fit
+fn to_offset(bytes: &[u8]) -> Option<usize> {
+    let buf = <[u8; 8]>::try_from(bytes).ok()?;
+    usize::try_from(u64::from_le_bytes(buf)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::v1::*;
+
+    // Builds the StrVec::write_to format by hand, so these tests don't need std
+    fn serialized(words: &[&str]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(words.len() as u64).to_le_bytes());
+        let mut end = 0;
+        for word in words {
+            end += word.len();
+            bytes.extend_from_slice(&(end as u64).to_le_bytes());
+        }
+        for word in words {
+            bytes.extend_from_slice(word.as_bytes());
+        }
+        bytes
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn matches_write_to() {
+        let words = ["a", "", "naïve"];
+        let strings: ::StrVec = words.iter().cloned().collect();
+        let mut bytes = Vec::new();
+        strings.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes, serialized(&words));
+    }
+
+    #[test]
+    fn view() {
+        let bytes = serialized(&["a", "", "naïve"]);
+        let view = StrVecView::new(&bytes).unwrap();
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.get(0), Some("a"));
+        assert_eq!(view.get(1), Some(""));
+        assert_eq!(view.get(2), Some("naïve"));
+        assert_eq!(view.get(3), None);
+        assert_eq!(view.iter().rev().collect::<Vec<_>>(), ["naïve", "", "a"]);
+        assert_eq!(format!("{:?}", view), r#"["a", "", "naïve"]"#);
+
+        let empty = serialized(&[]);
+        assert!(StrVecView::new(&empty).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        let bytes = serialized(&["a", "naïve"]);
+        assert!(StrVecView::new(&bytes[..bytes.len() - 1]).is_none());
+        assert!(StrVecView::new(&bytes[..4]).is_none());
+        assert!(StrVecView::new(&[]).is_none());
+
+        let mut trailing = bytes.clone();
+        trailing.push(b'x');
+        assert!(StrVecView::new(&trailing).is_none());
+
+        // Ends the first string in the middle of the ï
+        let mut split = bytes.clone();
+        split[8..16].copy_from_slice(&4u64.to_le_bytes());
+        assert!(StrVecView::new(&split).is_none());
+
+        let mut huge_count = bytes.clone();
+        huge_count[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(StrVecView::new(&huge_count).is_none());
+
+        // Too large for a usize on 32-bit targets, and past the data everywhere
+        for i in 0..2 {
+            let mut huge_offset = bytes.clone();
+            huge_offset[8 + i * 8..16 + i * 8].copy_from_slice(&u64::MAX.to_le_bytes());
+            assert!(StrVecView::new(&huge_offset).is_none());
+        }
+    }
+}
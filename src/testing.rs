@@ -0,0 +1,115 @@
+//! Proptest strategies for generating the containers in this crate.
+//!
+//! ```
+//! # extern crate panicless;
+//! # #[macro_use] extern crate proptest;
+//! use panicless::testing;
+//!
+//! proptest! {
+//!     fn joined_length(words in testing::str_vec(".{0,8}", 0..16)) {
+//!         let total: usize = words.iter().map(str::len).sum();
+//!         prop_assert!(total <= 8 * 4 * words.len());
+//!     }
+//! }
+//! # fn main() { joined_length(); }
+//! ```
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use proptest::collection::{self, SizeRange};
+use proptest::prelude::*;
+use proptest::sample::Index;
+use {ChillVec, CursorVec, StrVec};
+
+/// Generates a `ChillVec` with a length in `size`, filled by `element`.
+pub fn chill_vec<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ChillVec<S::Value>> {
+    collection::vec(element, size).prop_map(ChillVec::from)
+}
+
+/// Generates a `StrVec` holding a number of strings in `size`, each generated by `string`.
+pub fn str_vec<S>(string: S, size: impl Into<SizeRange>) -> impl Strategy<Value = StrVec>
+where
+    S: Strategy<Value = String>,
+{
+    collection::vec(string, size).prop_map(|strings| {
+        let mut vec = StrVec::new();
+        for s in &strings {
+            vec.push(s);
+        }
+        vec
+    })
+}
+
+/// Generates a `CursorVec` with a length in `size` and its cursor on any of the elements.
+///
+/// A `CursorVec` is never empty, so this generates at least one element whatever `size` says.
+pub fn cursor_vec<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = CursorVec<S::Value>> {
+    let (min, max) = size.into().start_end_incl();
+    // The first element is generated on its own, so the rest may be empty
+    let rest = min.saturating_sub(1)..=max.saturating_sub(1);
+    let element = Arc::new(element);
+    (
+        element.clone(),
+        collection::vec(element, rest),
+        any::<Index>(),
+    )
+        .prop_map(|(first, rest, cursor)| {
+            let mut vec = CursorVec::new(first);
+            for item in rest {
+                vec.push(item);
+            }
+            vec.seek(cursor.index(vec.len()));
+            vec
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::v1::*;
+
+    proptest::proptest! {
+        #[test]
+        fn chill_vec_iter(vec in chill_vec(any::<u32>(), 3..10)) {
+            proptest::prop_assert_eq!(vec.iter().count(), vec.len());
+            let copied: Vec<u32> = vec.iter().cloned().collect();
+            proptest::prop_assert_eq!(ChillVec::from(copied), vec);
+        }
+
+        #[test]
+        fn str_vec_indices(vec in str_vec(".{0,8}", 0..10)) {
+            // The spans tile the data buffer in order, and each one holds its string
+            let concat = vec.concat();
+            let mut expected_start = 0;
+            for (i, range, s) in vec.iter_spans() {
+                proptest::prop_assert_eq!(range.start, expected_start);
+                proptest::prop_assert_eq!(&concat[range.clone()], s.as_bytes());
+                proptest::prop_assert_eq!(vec.get(i), Some(s));
+                expected_start = range.end;
+            }
+            proptest::prop_assert_eq!(expected_start, vec.total_bytes());
+            proptest::prop_assert!(core::str::from_utf8(&concat).is_ok());
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn str_vec_roundtrip(vec in str_vec(".{0,8}", 0..10)) {
+            let mut buf = Vec::new();
+            proptest::prop_assert!(vec.write_to(&mut buf).is_ok());
+            let back = StrVec::read_from(&buf[..]);
+            proptest::prop_assert_eq!(back.ok(), Some(vec));
+        }
+
+        #[test]
+        fn cursor_vec_never_empty(vec in cursor_vec(any::<u8>(), 0..5)) {
+            proptest::prop_assert!(!vec.is_empty());
+            proptest::prop_assert!(vec.tell() < vec.len());
+        }
+    }
+}
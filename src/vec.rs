@@ -1,113 +1,214 @@
-use std::alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout};
-use std::mem::{align_of, size_of};
-use std::num::NonZeroUsize;
-use std::ptr::NonNull;
-use std::{ptr, slice};
+use alloc::alloc::{alloc, alloc_zeroed, dealloc, realloc, Layout};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, FusedIterator};
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of, MaybeUninit};
+use core::num::NonZeroUsize;
+use core::ops::{Bound, Range, RangeBounds};
+use core::ptr::NonNull;
+use core::{fmt, mem, ptr, slice};
+use growth::{Doubling, GrowthStrategy};
+use oom::handle_alloc_error;
 
-#[inline]
-fn alloc_or_abort<T>(n_elements: NonZeroUsize) -> NonNull<T> {
-    unsafe {
-        let layout =
-            Layout::from_size_align_unchecked(n_elements.get() * size_of::<T>(), align_of::<T>());
+/// The error returned by the fallible allocation APIs when the allocator fails
+/// or the requested capacity is too large to be allocated at all
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AllocError;
 
-        // Rust specifies that pointers cannot be offset by more than isize::MAX in bytes
-        // so we abort on attempts to allocate more than that amount of memory, because
-        // to do otherwise requires a bounds-check on every element access and produces
-        // unreachable data.
-        // If we omit this check there would be possible UB when accessing an
-        // element, and could actually happen today on a 32-bit platform
-        if layout.size() > isize::max_value() as usize {
-            handle_alloc_error(layout);
-        }
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
 
-        NonNull::new(alloc(layout) as *mut T).unwrap_or_else(|| handle_alloc_error(layout))
+#[cfg(feature = "std")]
+impl std::error::Error for AllocError {}
+
+/// Aborts because a capacity computation overflowed, so there isn't even a valid Layout describing
+/// what was asked for. The OOM hook sees the largest Layout possible for T instead.
+#[cold]
+#[inline(never)]
+pub(crate) fn capacity_overflow<T>() -> ! {
+    let align = align_of::<T>();
+    match Layout::from_size_align(isize::MAX as usize - (align - 1), align) {
+        Ok(layout) => handle_alloc_error(layout),
+        Err(_) => handle_alloc_error(Layout::new::<T>()),
     }
 }
 
+/// Adds to a length or capacity, aborting on overflow
+#[inline]
+fn add_or_abort<T>(len: usize, additional: usize) -> usize {
+    len.checked_add(additional)
+        .unwrap_or_else(|| capacity_overflow::<T>())
+}
+
+#[inline]
+fn array_layout_or_abort<T>(n_elements: NonZeroUsize) -> Layout {
+    // Rust specifies that pointers cannot be offset by more than isize::MAX in bytes
+    // so we abort on attempts to allocate more than that amount of memory, because
+    // to do otherwise requires a bounds-check on every element access and produces
+    // unreachable data.
+    // If we omit this check there would be possible UB when accessing an
+    // element, and could actually happen today on a 32-bit platform.
+    // Layout::array checks that, and that the multiplication to get there didn't overflow.
+    Layout::array::<T>(n_elements.get()).unwrap_or_else(|_| capacity_overflow::<T>())
+}
+
+#[inline]
+fn alloc_or_abort<T>(n_elements: NonZeroUsize) -> NonNull<T> {
+    let layout = array_layout_or_abort::<T>(n_elements);
+    NonNull::new(unsafe { alloc(layout) } as *mut T).unwrap_or_else(|| handle_alloc_error(layout))
+}
+
+#[inline]
+fn alloc_zeroed_or_abort<T>(n_elements: NonZeroUsize) -> NonNull<T> {
+    let layout = array_layout_or_abort::<T>(n_elements);
+    NonNull::new(unsafe { alloc_zeroed(layout) } as *mut T)
+        .unwrap_or_else(|| handle_alloc_error(layout))
+}
+
 #[inline]
 fn realloc_or_abort<T>(
     ptr: NonNull<T>,
     previous_size: NonZeroUsize,
     new_size: NonZeroUsize,
 ) -> NonNull<T> {
+    let new_layout = array_layout_or_abort::<T>(new_size);
     unsafe {
+        // This layout was already used to allocate ptr, so it must be valid
         let old_layout = Layout::from_size_align_unchecked(
             previous_size.get() * size_of::<T>(),
             align_of::<T>(),
         );
+        NonNull::new(realloc(ptr.cast().as_ptr(), old_layout, new_layout.size()) as *mut T)
+            .unwrap_or_else(|| handle_alloc_error(new_layout))
+    }
+}
 
-        if old_layout.size() > isize::max_value() as usize {
-            handle_alloc_error(old_layout);
-        }
+#[inline]
+fn try_alloc<T>(n_elements: NonZeroUsize) -> Result<NonNull<T>, AllocError> {
+    // Layout::array rejects sizes that overflow or exceed isize::MAX
+    let layout = Layout::array::<T>(n_elements.get()).map_err(|_| AllocError)?;
+    NonNull::new(unsafe { alloc(layout) } as *mut T).ok_or(AllocError)
+}
 
-        NonNull::new(realloc(
-            ptr.cast().as_ptr(),
-            old_layout,
-            new_size.get() * size_of::<T>(),
-        ) as *mut T)
-        .unwrap_or_else(|| {
-            handle_alloc_error(Layout::from_size_align_unchecked(
-                new_size.get(),
-                align_of::<T>(),
-            ))
-        })
+#[inline]
+fn try_realloc<T>(
+    ptr: NonNull<T>,
+    previous_size: NonZeroUsize,
+    new_size: NonZeroUsize,
+) -> Result<NonNull<T>, AllocError> {
+    let new_layout = Layout::array::<T>(new_size.get()).map_err(|_| AllocError)?;
+    unsafe {
+        // This layout was already used to allocate ptr, so it must be valid
+        let old_layout = Layout::from_size_align_unchecked(
+            previous_size.get() * size_of::<T>(),
+            align_of::<T>(),
+        );
+        NonNull::new(realloc(ptr.cast().as_ptr(), old_layout, new_layout.size()) as *mut T)
+            .ok_or(AllocError)
+    }
+}
+
+/// Converts any range into a `Range` over `0..len`, or `None` if it is backwards or out of bounds
+#[inline]
+pub(crate) fn checked_range<R: RangeBounds<usize>>(range: R, len: usize) -> Option<Range<usize>> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1)?,
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    if start <= end && end <= len {
+        Some(start..end)
+    } else {
+        None
     }
 }
 
+/// A growable array that aborts instead of panicking when it can't allocate.
+///
+/// `G` decides how the capacity grows when the vector runs out of room, see `GrowthStrategy`.
 #[derive(Debug)]
-pub struct ChillVec<T> {
+pub struct ChillVec<T, G = Doubling> {
     data: NonNull<T>,
     length: usize,
     capacity: usize,
+    // Tells drop check that we own values of type T and will drop them
+    marker: PhantomData<T>,
+    growth: PhantomData<G>,
 }
 
-impl<T> Default for ChillVec<T> {
+// ChillVec owns its elements just like a Vec, so it's as thread-safe as they are
+unsafe impl<T: Send, G> Send for ChillVec<T, G> {}
+unsafe impl<T: Sync, G> Sync for ChillVec<T, G> {}
+
+impl<T, G> Default for ChillVec<T, G> {
     #[inline]
     fn default() -> Self {
-        Self::new()
+        Self::with_growth()
     }
 }
 
-impl<T> Clone for ChillVec<T>
+impl<T, G> Clone for ChillVec<T, G>
 where
     T: Clone,
+    G: GrowthStrategy,
 {
     #[inline]
     fn clone(&self) -> Self {
-        // This is not an optimization, it's required because
-        // the layout provided to alloc must have non-zero size
-        let data = match NonZeroUsize::new(self.length) {
-            Some(n) => alloc_or_abort(n),
-            None => return Self::new(),
-        };
+        // extend_from_slice compiles down to a memcpy when T is Copy
+        let mut vec = Self::with_capacity_and_growth(self.length);
+        vec.extend_from_slice(self);
+        vec
+    }
 
-        unsafe {
-            std::ptr::copy_nonoverlapping(self.data.as_ptr(), data.as_ptr(), self.length);
-        };
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.length);
 
-        Self {
-            length: self.length,
-            capacity: self.length,
-            data,
+        // Clone into the elements we already have, so their resources (such as the buffer of a
+        // String) can be reused, then append clones of whatever is left
+        for (dst, src) in self.iter_mut().zip(source.iter()) {
+            dst.clone_from(src);
+        }
+        if let Some(rest) = source.get(self.length..) {
+            self.extend_from_slice(rest);
         }
     }
 }
 
 impl<T> ChillVec<T> {
+    /// An empty vector, usable in constants and statics.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// static NOTHING: ChillVec<u32> = ChillVec::EMPTY;
+    /// assert!(NOTHING.is_empty());
+    /// ```
+    pub const EMPTY: Self = Self::new();
+
     /// ```
     /// # use panicless::ChillVec;
     /// let vec = ChillVec::<usize>::new();
     /// assert!(vec.len() == 0);
     /// assert!(vec.capacity() == 0);
+    ///
+    /// // This doesn't allocate, so it can initialize a static
+    /// static TABLE: ChillVec<usize> = ChillVec::new();
+    /// assert!(TABLE.is_empty());
     /// ```
     #[inline]
-    pub fn new() -> Self {
-        assert!(size_of::<T>() > 0);
-        Self {
-            data: NonNull::dangling(),
-            length: 0,
-            capacity: 0,
-        }
+    pub const fn new() -> Self {
+        Self::with_growth()
     }
 
     /// ```
@@ -119,25 +220,194 @@ impl<T> ChillVec<T> {
     /// ```
     #[inline]
     pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_growth(cap)
+    }
+
+    /// Creates a `ChillVec` directly from a pointer, a length, and a capacity.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null and aligned for `T`. If `capacity` is non-zero, `ptr` must have been
+    /// allocated by the global allocator for exactly `capacity` elements of `T`, such as by
+    /// another `ChillVec` or a `std::vec::Vec`. The first `length` elements must be initialized,
+    /// and `length` must not exceed `capacity`. Ownership of the allocation is transferred to the
+    /// returned vector.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    /// let (ptr, len, cap) = vec.into_raw_parts();
+    /// let vec = unsafe { ChillVec::from_raw_parts(ptr, len, cap) };
+    /// assert_eq!(vec[..], [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw_parts(ptr: *mut T, length: usize, capacity: usize) -> Self {
+        Self {
+            data: NonNull::new_unchecked(ptr),
+            length,
+            capacity,
+            marker: PhantomData,
+            growth: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> ChillVec<T> {
+    /// Creates a vector of `n` clones of `value`, allocating exactly once.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let vec = ChillVec::from_elem("hi", 3);
+    /// assert_eq!(vec, ["hi", "hi", "hi"]);
+    /// assert_eq!(vec.capacity(), 3);
+    /// ```
+    #[inline]
+    pub fn from_elem(value: T, n: usize) -> Self {
+        let mut vec = Self::with_capacity(n);
+        vec.resize(n, value);
+        vec
+    }
+}
+
+impl<T: Copy> ChillVec<T> {
+    /// Creates a vector of `n` elements whose bytes are all zero, getting the memory already
+    /// zeroed from the allocator instead of writing every element.
+    ///
+    /// # Safety
+    ///
+    /// All-zero bytes must be a valid value of `T`, as with `core::mem::zeroed`. This holds for
+    /// the integer and float types, but not for references or the `NonZero` types.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let buffer = unsafe { ChillVec::<u8>::zeroed(4096) };
+    /// assert_eq!(buffer.len(), 4096);
+    /// assert!(buffer.iter().all(|&b| b == 0));
+    /// ```
+    #[inline]
+    pub unsafe fn zeroed(n: usize) -> Self {
+        assert!(size_of::<T>() > 0);
+
+        let data = match NonZeroUsize::new(n) {
+            Some(n) => alloc_zeroed_or_abort(n),
+            None => return Self::new(),
+        };
+
+        Self {
+            data,
+            length: n,
+            capacity: n,
+            marker: PhantomData,
+            growth: PhantomData,
+        }
+    }
+}
+
+impl<T, G> ChillVec<T, G> {
+    /// Like `new`, but for a vector with a growth strategy other than the default.
+    ///
+    /// ```
+    /// # use panicless::{ChillVec, Exact};
+    /// let mut vec = ChillVec::<u8, Exact>::with_growth();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// assert_eq!(vec.capacity(), 2);
+    /// ```
+    #[inline]
+    pub const fn with_growth() -> Self {
+        assert!(size_of::<T>() > 0);
+        Self {
+            data: NonNull::dangling(),
+            length: 0,
+            capacity: 0,
+            marker: PhantomData,
+            growth: PhantomData,
+        }
+    }
+
+    /// Like `with_capacity`, but for a vector with a growth strategy other than the default.
+    #[inline]
+    pub fn with_capacity_and_growth(cap: usize) -> Self {
         assert!(size_of::<T>() > 0);
 
         let data = match NonZeroUsize::new(cap) {
             Some(n) => alloc_or_abort(n),
-            None => return Self::new(),
+            None => return Self::with_growth(),
         };
 
         Self {
             data,
             length: 0,
             capacity: cap,
+            marker: PhantomData,
+            growth: PhantomData,
+        }
+    }
+
+    /// Switches to a different growth strategy, keeping the elements and the allocation.
+    #[inline]
+    pub fn into_growth<H>(self) -> ChillVec<T, H> {
+        let vec = mem::ManuallyDrop::new(self);
+        ChillVec {
+            data: vec.data,
+            length: vec.length,
+            capacity: vec.capacity,
+            marker: PhantomData,
+            growth: PhantomData,
         }
     }
+}
 
+impl<T, G: GrowthStrategy> ChillVec<T, G> {
     #[inline]
     pub fn capacity(&self) -> usize {
         self.capacity
     }
 
+    /// The number of bytes this vector has allocated on the heap, including unused capacity.
+    ///
+    /// This only counts the vector's own buffer, not any heap memory owned by its elements.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let vec = ChillVec::<u32>::with_capacity(10);
+    /// assert_eq!(vec.heap_size(), 40);
+    /// ```
+    #[inline]
+    pub fn heap_size(&self) -> usize {
+        // Can't overflow, the allocation was checked against isize::MAX when it was made
+        self.capacity * size_of::<T>()
+    }
+
+    /// Decomposes the vector into its pointer, length, and capacity without freeing anything.
+    /// The caller becomes responsible for the allocation, which can be handed back to
+    /// `from_raw_parts`.
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let vec = mem::ManuallyDrop::new(self);
+        (vec.data.as_ptr(), vec.length, vec.capacity)
+    }
+
+    /// Returns a pointer to the buffer, which is dangling if nothing has been allocated
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the buffer, which is dangling if nothing has been allocated
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_ptr()
+    }
+
+    /// Ensures there is space for at least `additional` more elements.
+    ///
+    /// When the vector has to reallocate, the new capacity comes from the growth strategy. With
+    /// the default `Doubling` strategy growth is amortized: the capacity at least doubles, so a
+    /// sequence of small reservations (such as the ones done by `push`) only reallocates
+    /// O(log n) times. Use `reserve_exact` to allocate no more than requested.
+    ///
     /// ```
     /// # use panicless::ChillVec;
     /// let mut vec = ChillVec::<usize>::new();
@@ -147,14 +417,47 @@ impl<T> ChillVec<T> {
     /// assert!(vec.capacity() >= 20);
     /// ```
     #[inline]
-    pub fn reserve(&mut self, new_capacity: usize) {
-        // Save silly users from themselves
-        // This has a small additional cost on every call, but may save an entire allocation
-        // If this function is made private we could remove the check
-        if new_capacity <= self.capacity {
-            return;
+    pub fn reserve(&mut self, additional: usize) {
+        if additional > self.capacity - self.length {
+            self.grow_amortized(additional);
+        }
+    }
+
+    /// Ensures there is space for at least `additional` more elements, without speculatively
+    /// over-allocating.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::<usize>::new();
+    /// vec.push(1);
+    /// vec.reserve_exact(20);
+    /// assert!(vec.capacity() == 21);
+    /// ```
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        if additional > self.capacity - self.length {
+            self.grow_to(add_or_abort::<T>(self.length, additional));
         }
+    }
+
+    /// The capacity to grow to when at least `required` is needed, according to the growth
+    /// strategy. Never less than `required`, whatever the strategy says.
+    #[inline]
+    fn amortized_capacity(&self, required: usize) -> usize {
+        G::grow(self.capacity, required).max(required)
+    }
+
+    /// The slow path of `reserve` and `push`, kept out of line so the fast path at every call site
+    /// is just a comparison
+    #[cold]
+    #[inline(never)]
+    fn grow_amortized(&mut self, additional: usize) {
+        let new_capacity = self.amortized_capacity(add_or_abort::<T>(self.length, additional));
+        self.grow_to(new_capacity);
+    }
 
+    /// Reallocates to exactly `new_capacity`, which must be larger than the current capacity
+    fn grow_to(&mut self, new_capacity: usize) {
         let new_capacity = match NonZeroUsize::new(new_capacity) {
             Some(n) => n,
             None => return,
@@ -169,6 +472,70 @@ impl<T> ChillVec<T> {
         self.capacity = new_capacity.get();
     }
 
+    /// Returns the unused part of the buffer, for initializing elements in place before
+    /// committing them with `set_len`.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::<u8>::with_capacity(4);
+    /// for (i, slot) in vec.spare_capacity_mut()[..3].iter_mut().enumerate() {
+    ///     *slot = std::mem::MaybeUninit::new(i as u8);
+    /// }
+    /// unsafe { vec.set_len(3) };
+    /// assert_eq!(vec, [0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.data.as_ptr().add(self.length) as *mut MaybeUninit<T>,
+                self.capacity - self.length,
+            )
+        }
+    }
+
+    /// Sets the length of the vector without dropping or initializing anything.
+    ///
+    /// # Safety
+    ///
+    /// `new_len` must not exceed the capacity, and the elements up to `new_len` must be
+    /// initialized. Elements beyond `new_len` are forgotten, not dropped.
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        self.length = new_len;
+    }
+
+    /// Ensures there is space for at least `additional` more elements, reporting allocation
+    /// failure to the caller instead of aborting.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::<u64>::new();
+    /// assert!(vec.try_reserve(20).is_ok());
+    /// assert!(vec.capacity() >= 20);
+    /// assert!(vec.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        let required = self.length.checked_add(additional).ok_or(AllocError)?;
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        // Grows the same way reserve does, so new_capacity > capacity >= 0 can't be zero
+        let new_capacity = match NonZeroUsize::new(self.amortized_capacity(required)) {
+            Some(n) => n,
+            None => return Ok(()),
+        };
+
+        self.data = match NonZeroUsize::new(self.capacity) {
+            None => try_alloc(new_capacity)?,
+            Some(old_capacity) => try_realloc(self.data, old_capacity, new_capacity)?,
+        };
+        self.capacity = new_capacity.get();
+
+        Ok(())
+    }
+
     /// ```
     /// # use panicless::ChillVec;
     /// let mut vec = ChillVec::new();
@@ -182,8 +549,7 @@ impl<T> ChillVec<T> {
     #[inline]
     pub fn push(&mut self, item: T) {
         if self.length == self.capacity {
-            let new_capacity = self.capacity + self.capacity / 2 + 1;
-            self.reserve(new_capacity)
+            self.grow_amortized(1);
         }
 
         unsafe {
@@ -192,102 +558,1565 @@ impl<T> ChillVec<T> {
         self.length += 1;
     }
 
-    // TODO This is possibly wrong, RawVec has a bajillion checks
-    pub fn shrink_to_fit(&mut self) {
-        if self.length > 0 && self.capacity > self.length {
-            unsafe {
-                let old_size = size_of::<T>() * self.capacity;
-                let new_size = size_of::<T>() * self.length;
-                let align = align_of::<T>();
-                let old_layout = Layout::from_size_align_unchecked(old_size, align);
-
-                self.data = NonNull::new(
-                    realloc(self.data.cast().as_ptr(), old_layout, new_size) as *mut T
-                )
-                .unwrap_or_else(|| {
-                    handle_alloc_error(Layout::from_size_align_unchecked(new_size, align))
-                });
-                self.capacity = self.length;
-            }
+    /// Like `push`, but if the vector needs to grow and the allocation fails,
+    /// the item is handed back instead of aborting.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// assert_eq!(vec.try_push(1), Ok(()));
+    /// assert_eq!(vec[..], [1]);
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        if self.length == self.capacity && self.try_reserve(1).is_err() {
+            return Err(item);
+        }
+
+        unsafe {
+            ptr::write(self.data.as_ptr().add(self.length), item);
         }
+        self.length += 1;
+        Ok(())
     }
-}
 
-impl<T: Copy> ChillVec<T> {
+    /// Shortens the vector to `len` elements, dropping the rest.
+    /// Does nothing if the vector is already that short.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec: ChillVec<i32> = (1..6).collect();
+    /// vec.truncate(2);
+    /// assert_eq!(vec, [1, 2]);
+    /// vec.truncate(10);
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
     #[inline]
-    pub fn extend_from_slice(&mut self, items: &[T]) {
-        let new_len = self.length + items.len();
-        if new_len > self.capacity() {
-            self.reserve(new_len + 1);
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return;
         }
 
+        let tail_len = self.length - len;
+        // Shorten first, so if a destructor panics we leak instead of dropping anything twice
+        self.length = len;
         unsafe {
-            ptr::copy_nonoverlapping(
-                items.as_ptr(),
-                self.data.as_ptr().add(self.length),
-                items.len(),
-            );
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.data.as_ptr().add(len),
+                tail_len,
+            ));
         }
-
-        self.length = new_len;
     }
-}
 
-impl<T> Drop for ChillVec<T> {
+    /// Drops all the elements, keeping the allocation for reuse.
     #[inline]
-    fn drop(&mut self) {
-        // If capacity is 0 no allocation was done and the pointer is dangling
-        if self.capacity > 0 {
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Resizes the vector to `new_len`, filling new slots with the results of calling `f`
+    /// and dropping any elements past the new length.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// let mut next = 0;
+    /// vec.resize_with(3, || { next += 1; next });
+    /// assert_eq!(vec[..], [1, 2, 3]);
+    /// vec.resize_with(1, || 0);
+    /// assert_eq!(vec[..], [1]);
+    /// ```
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        if new_len <= self.length {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.length);
+        while self.length < new_len {
             unsafe {
-                dealloc(
-                    self.data.cast().as_ptr(),
-                    Layout::from_size_align_unchecked(
-                        size_of::<T>() * self.capacity,
-                        align_of::<T>(),
-                    ),
-                );
+                ptr::write(self.data.as_ptr().add(self.length), f());
             }
+            self.length += 1;
         }
     }
-}
-
-impl<T> std::ops::Deref for ChillVec<T> {
-    type Target = [T];
 
+    /// Shrinks the capacity to the length, freeing the allocation entirely if the vector is empty.
     #[inline]
-    fn deref(&self) -> &[T] {
-        unsafe { slice::from_raw_parts(self.data.as_ptr(), self.length) }
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0)
     }
-}
 
-impl<T> std::ops::DerefMut for ChillVec<T> {
-    #[inline]
-    fn deref_mut(&mut self) -> &mut [T] {
-        unsafe { slice::from_raw_parts_mut(self.data.as_ptr(), self.length) }
-    }
-}
+    /// Shrinks the capacity to `min_capacity`, or to the length if that is larger, keeping some
+    /// headroom for future pushes. Does nothing if the capacity is already at or below that.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::<u8>::with_capacity(100);
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    /// vec.shrink_to(10);
+    /// assert_eq!(vec.capacity(), 10);
+    /// vec.shrink_to(0);
+    /// assert_eq!(vec.capacity(), 3);
+    /// ```
+    // TODO This is possibly wrong, RawVec has a bajillion checks
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let target = self.length.max(min_capacity);
+        if target >= self.capacity {
+            return;
+        }
 
-impl<'a, T> IntoIterator for &'a ChillVec<T> {
-    type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+        // capacity > target, so it can't be zero
+        let old_capacity = match NonZeroUsize::new(self.capacity) {
+            Some(n) => n,
+            None => return,
+        };
 
-    fn into_iter(self) -> std::slice::Iter<'a, T> {
-        self.iter()
-    }
-}
+        match NonZeroUsize::new(target) {
+            Some(new_capacity) => {
+                self.data = realloc_or_abort(self.data, old_capacity, new_capacity);
+            }
+            None => {
+                unsafe {
+                    dealloc(
+                        self.data.cast().as_ptr(),
+                        Layout::from_size_align_unchecked(
+                            size_of::<T>() * self.capacity,
+                            align_of::<T>(),
+                        ),
+                    );
+                }
+                self.data = NonNull::dangling();
+            }
+        }
 
-impl<'a, T> IntoIterator for &'a mut ChillVec<T> {
-    type Item = &'a mut T;
-    type IntoIter = std::slice::IterMut<'a, T>;
+        self.capacity = target;
+    }
 
-    fn into_iter(self) -> std::slice::IterMut<'a, T> {
-        self.iter_mut()
+    /// Shrinks the allocation to fit and converts it into a boxed slice without copying.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::with_capacity(10);
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    /// let boxed: Box<[i32]> = vec.into_boxed_slice();
+    /// assert_eq!(*boxed, [1, 2, 3]);
+    /// ```
+    pub fn into_boxed_slice(mut self) -> Box<[T]> {
+        self.shrink_to_fit();
+        let vec = mem::ManuallyDrop::new(self);
+        // The allocation is now exactly the Layout of a [T] of this length, which is what Box
+        // expects. If the vector is empty there is no allocation and the pointer is dangling.
+        unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(vec.data.as_ptr(), vec.length)) }
     }
-}
+
+    /// Consumes the vector without running its destructor, returning a reference to its contents
+    /// that lives for as long as the caller wants, up to `'static`.
+    ///
+    /// The allocation, including any spare capacity, is never freed. Call `shrink_to_fit` first
+    /// to avoid leaking the unused capacity along with the elements.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    /// let table: &'static mut [i32] = vec.leak();
+    /// table[0] = 4;
+    /// assert_eq!(table, [4, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut [T] {
+        let vec = mem::ManuallyDrop::new(self);
+        unsafe { slice::from_raw_parts_mut(vec.data.as_ptr(), vec.length) }
+    }
+
+    /// Swaps two elements, returning `false` and leaving the vector alone if either index is out
+    /// of bounds. This shadows the slice method, which panics instead.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::from([1, 2, 3]);
+    /// assert!(vec.swap(0, 2));
+    /// assert_eq!(vec, [3, 2, 1]);
+    /// assert!(!vec.swap(0, 3));
+    /// ```
+    #[inline]
+    pub fn swap(&mut self, i: usize, j: usize) -> bool {
+        if i >= self.length || j >= self.length {
+            return false;
+        }
+        // ptr::swap allows the two to be the same element
+        unsafe {
+            let data = self.data.as_ptr();
+            ptr::swap(data.add(i), data.add(j));
+        }
+        true
+    }
+
+    /// Returns mutable references to several elements at once, or `None` if any index is out
+    /// of bounds or appears more than once.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec: ChillVec<i32> = (0..5).collect();
+    /// if let Some([a, b]) = vec.get_many_mut([1, 3]) {
+    ///     std::mem::swap(a, b);
+    /// }
+    /// assert_eq!(vec, [0, 3, 2, 1, 4]);
+    /// assert!(vec.get_many_mut([2, 2]).is_none());
+    /// assert!(vec.get_many_mut([0, 5]).is_none());
+    /// ```
+    #[inline]
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        for (i, index) in indices.iter().enumerate() {
+            if *index >= self.length || indices.iter().take(i).any(|prev| prev == index) {
+                return None;
+            }
+        }
+
+        let data = self.data.as_ptr();
+        // Every index is in bounds and distinct, so none of these references alias
+        Some(indices.map(|index| unsafe { &mut *data.add(index) }))
+    }
+
+    /// Binary searches a vector sorted according to `f`, which reports whether an element is less
+    /// than, equal to, or greater than the target. Returns `Ok` with the index of a match, or
+    /// `Err` with the index the target could be inserted at to keep the vector sorted.
+    ///
+    /// Unlike the slice method this is written against the raw buffer, so the only way for it to
+    /// panic is for `f` to panic.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.length;
+        while low < high {
+            // Can't overflow because high <= len <= isize::MAX
+            let mid = low + (high - low) / 2;
+            // low <= mid < high <= len, so mid is in bounds
+            let item = unsafe { &*self.data.as_ptr().add(mid) };
+            match f(item) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Returns the index of the first element for which `pred` is false, assuming the vector is
+    /// partitioned so that all the elements for which it is true come first.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let vec = ChillVec::from([1, 2, 3, 10, 20]);
+    /// assert_eq!(vec.partition_point(|x| *x < 5), 3);
+    /// ```
+    #[inline]
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        // Never reports Equal, so this always finds the boundary
+        match self.binary_search_by(|x| {
+            if pred(x) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }) {
+            Ok(i) | Err(i) => i,
+        }
+    }
+
+    /// Removes the elements in `range` from the vector, returning them through an iterator.
+    /// Returns `None` if the range is out of bounds instead of panicking.
+    ///
+    /// The removed elements are dropped if the iterator is not fully consumed.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    /// let drained: Vec<i32> = vec.drain(1..3).unwrap().collect();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(vec[..], [1, 4, 5]);
+    /// assert!(vec.drain(2..4).is_none());
+    /// ```
+    #[inline]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Option<ChillDrain<'_, T, G>> {
+        let Range { start, end } = checked_range(range, self.length)?;
+        let tail_len = self.length - end;
+
+        // If the Drain is leaked, the drained elements and the tail are leaked along with it
+        // instead of being exposed in a moved-from state
+        self.length = start;
+
+        Some(ChillDrain {
+            vec: self,
+            front: start,
+            back: end,
+            tail_start: end,
+            tail_len,
+        })
+    }
+
+    /// Replaces the elements in `range` with the items of `replace_with`, returning the removed
+    /// elements through an iterator. Returns `None` if the range is out of bounds.
+    ///
+    /// The replacement happens when the returned iterator is dropped, whether or not the removed
+    /// elements were consumed.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[1, 2, 3, 4]);
+    /// let removed: Vec<i32> = vec.splice(1..3, vec![7, 8, 9]).unwrap().collect();
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(vec[..], [1, 7, 8, 9, 4]);
+    /// ```
+    #[inline]
+    pub fn splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Option<ChillSplice<'_, I::IntoIter, G>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        Some(ChillSplice {
+            drain: self.drain(range)?,
+            replace_with: replace_with.into_iter(),
+        })
+    }
+
+    /// Moves all the elements of `other` onto the end of this vector, leaving `other` empty.
+    /// `other` keeps its allocation.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut a: ChillVec<i32> = (1..3).collect();
+    /// let mut b: ChillVec<i32> = (3..5).collect();
+    /// a.append(&mut b);
+    /// assert_eq!(a, [1, 2, 3, 4]);
+    /// assert!(b.is_empty());
+    /// ```
+    #[inline]
+    pub fn append(&mut self, other: &mut Self) {
+        let count = other.length;
+        self.reserve(count);
+        unsafe {
+            ptr::copy_nonoverlapping(
+                other.data.as_ptr(),
+                self.data.as_ptr().add(self.length),
+                count,
+            );
+        }
+        // The elements now belong to self
+        other.length = 0;
+        self.length += count;
+    }
+
+    /// Moves the elements from `at` onwards into a newly allocated vector.
+    /// Returns `None` if `at > len` instead of panicking.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    /// let tail = vec.split_off(1).unwrap();
+    /// assert_eq!(vec[..], [1]);
+    /// assert_eq!(tail[..], [2, 3]);
+    /// assert!(vec.split_off(2).is_none());
+    /// ```
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Option<Self> {
+        if at > self.length {
+            return None;
+        }
+
+        let tail_len = self.length - at;
+        let mut other = Self::with_capacity_and_growth(tail_len);
+        unsafe {
+            ptr::copy_nonoverlapping(self.data.as_ptr().add(at), other.data.as_ptr(), tail_len);
+        }
+        self.length = at;
+        other.length = tail_len;
+
+        Some(other)
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, dropping the rest in a single pass.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    /// vec.retain(|x| x % 2 == 1);
+    /// assert_eq!(vec[..], [1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| f(item))
+    }
+
+    /// Like `retain`, but the predicate may mutate the elements it keeps.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let len = self.length;
+        // If f panics we leak the remaining elements instead of dropping anything twice
+        self.length = 0;
+
+        let mut kept = 0;
+        unsafe {
+            let data = self.data.as_ptr();
+            for i in 0..len {
+                let item = data.add(i);
+                if f(&mut *item) {
+                    if i != kept {
+                        ptr::copy_nonoverlapping(item, data.add(kept), 1);
+                    }
+                    kept += 1;
+                } else {
+                    ptr::drop_in_place(item);
+                }
+            }
+        }
+
+        self.length = kept;
+    }
+
+    /// Returns an iterator that removes and yields the elements for which `filter` returns `true`,
+    /// compacting the kept elements as it goes.
+    ///
+    /// Elements the iterator hasn't reached yet when it is dropped are kept.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::from([1, 2, 3, 4, 5, 6]);
+    /// let evens: ChillVec<_> = vec.extract_if(|x| *x % 2 == 0).collect();
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// assert_eq!(vec, [1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn extract_if<F>(&mut self, filter: F) -> ChillExtractIf<'_, T, F, G>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let old_len = self.length;
+        // See retain_mut: if the iterator is leaked we leak the elements too
+        self.length = 0;
+        ChillExtractIf {
+            vec: self,
+            index: 0,
+            removed: 0,
+            old_len,
+            filter,
+        }
+    }
+
+    /// Removes consecutive elements for which `same_bucket(current, previous)` returns `true`,
+    /// keeping the first of each run.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let len = self.length;
+        if len <= 1 {
+            return;
+        }
+        // See retain_mut: leak instead of double-drop if the closure panics
+        self.length = 0;
+
+        let mut kept = 1;
+        unsafe {
+            let data = self.data.as_ptr();
+            for i in 1..len {
+                let item = data.add(i);
+                if same_bucket(&mut *item, &mut *data.add(kept - 1)) {
+                    ptr::drop_in_place(item);
+                } else {
+                    if i != kept {
+                        ptr::copy_nonoverlapping(item, data.add(kept), 1);
+                    }
+                    kept += 1;
+                }
+            }
+        }
+
+        self.length = kept;
+    }
+
+    /// Removes consecutive elements that map to the same key.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[10, 11, 20, 30, 31, 12]);
+    /// vec.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(vec[..], [10, 20, 30, 12]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+}
+
+impl<T: PartialEq, G: GrowthStrategy> ChillVec<T, G> {
+    /// Removes consecutive repeated elements.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[1, 1, 2, 3, 3, 3, 1]);
+    /// vec.dedup();
+    /// assert_eq!(vec[..], [1, 2, 3, 1]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b)
+    }
+}
+
+impl<T: PartialOrd, G: GrowthStrategy> ChillVec<T, G> {
+    /// Checks if the elements are in non-decreasing order.
+    #[inline]
+    pub fn is_sorted(&self) -> bool {
+        self.iter().zip(self.iter().skip(1)).all(|(a, b)| a <= b)
+    }
+}
+
+impl<T: Ord, G: GrowthStrategy> ChillVec<T, G> {
+    /// Binary searches a sorted vector for `x`, see `binary_search_by`.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let vec = ChillVec::from([1, 3, 5]);
+    /// assert_eq!(vec.binary_search(&3), Ok(1));
+    /// assert_eq!(vec.binary_search(&4), Err(2));
+    /// ```
+    #[inline]
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        self.binary_search_by(|item| item.cmp(x))
+    }
+}
+
+impl<T: Clone, G: GrowthStrategy> ChillVec<T, G> {
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.extend_from_slice(&[String::from("a"), String::from("b")]);
+    /// assert_eq!(vec[..], ["a", "b"]);
+    /// ```
+    #[inline]
+    pub fn extend_from_slice(&mut self, items: &[T]) {
+        self.reserve(items.len());
+
+        // For T: Copy the clones are plain copies and this loop optimizes to a memcpy
+        for item in items {
+            unsafe {
+                ptr::write(self.data.as_ptr().add(self.length), item.clone());
+            }
+            // Bumped one at a time so a panicking clone leaves us with only initialized elements
+            self.length += 1;
+        }
+    }
+
+    /// Appends clones of the elements in `src` to the end of the vector, returning `false` and
+    /// leaving the vector alone if the range is out of bounds.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::from([1, 2, 3]);
+    /// assert!(vec.extend_from_within(1..));
+    /// assert_eq!(vec, [1, 2, 3, 2, 3]);
+    /// assert!(!vec.extend_from_within(4..6));
+    /// ```
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R) -> bool {
+        let Range { start, end } = match checked_range(src, self.length) {
+            Some(range) => range,
+            None => return false,
+        };
+
+        self.reserve(end - start);
+        for i in start..end {
+            unsafe {
+                let data = self.data.as_ptr();
+                let item = (*data.add(i)).clone();
+                ptr::write(data.add(self.length), item);
+            }
+            self.length += 1;
+        }
+        true
+    }
+
+    /// Overwrites every element with a clone of `value`, dropping the old ones.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::from([1, 2, 3]);
+    /// vec.fill(0);
+    /// assert_eq!(vec, [0, 0, 0]);
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        if let Some((last, rest)) = self.split_last_mut() {
+            for item in rest {
+                item.clone_from(&value);
+            }
+            *last = value;
+        }
+    }
+
+    /// Gathers clones of the elements at `indices` into a new vector, in the order given.
+    /// Indices may repeat. Returns `None` without allocating if any index is out of bounds.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let vec = ChillVec::from(['a', 'b', 'c']);
+    /// assert_eq!(vec.select(&[2, 0, 0]).unwrap(), ['c', 'a', 'a']);
+    /// assert_eq!(vec.select(&[1, 3]), None);
+    /// ```
+    pub fn select(&self, indices: &[usize]) -> Option<Self> {
+        if indices.iter().any(|&i| i >= self.length) {
+            return None;
+        }
+
+        let mut selected = Self::with_capacity_and_growth(indices.len());
+        for &i in indices {
+            unsafe {
+                ptr::write(
+                    selected.data.as_ptr().add(selected.length),
+                    self.get_unchecked(i).clone(),
+                );
+            }
+            // Bumped one at a time so a panicking clone leaves us with only initialized elements
+            selected.length += 1;
+        }
+        Some(selected)
+    }
+
+    /// Resizes the vector to `new_len`, filling new slots with clones of `value`
+    /// and dropping any elements past the new length.
+    ///
+    /// ```
+    /// # use panicless::ChillVec;
+    /// let mut vec = ChillVec::new();
+    /// vec.resize(3, 'a');
+    /// assert_eq!(vec[..], ['a', 'a', 'a']);
+    /// vec.resize(2, 'b');
+    /// assert_eq!(vec[..], ['a', 'a']);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if new_len <= self.length {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.length);
+        // Clone into all but the last new slot, then move value into that one
+        while self.length + 1 < new_len {
+            unsafe {
+                ptr::write(self.data.as_ptr().add(self.length), value.clone());
+            }
+            self.length += 1;
+        }
+        unsafe {
+            ptr::write(self.data.as_ptr().add(self.length), value);
+        }
+        self.length += 1;
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T, G> Drop for ChillVec<T, G> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.drop_contents() }
+    }
+}
+
+// Like Vec, we promise not to touch the elements while dropping them beyond dropping them, so
+// they may contain references that have already dangled (as long as T's own Drop doesn't care)
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T, G> Drop for ChillVec<T, G> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.drop_contents() }
+    }
+}
+
+impl<T, G> ChillVec<T, G> {
+    /// Drops all the elements and frees the allocation, leaving self dangling
+    #[inline]
+    unsafe fn drop_contents(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.data.as_ptr(),
+                self.length,
+            ));
+        }
+
+        // If capacity is 0 no allocation was done and the pointer is dangling
+        if self.capacity > 0 {
+            unsafe {
+                dealloc(
+                    self.data.cast().as_ptr(),
+                    Layout::from_size_align_unchecked(
+                        size_of::<T>() * self.capacity,
+                        align_of::<T>(),
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl<T, G> core::ops::Deref for ChillVec<T, G> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.data.as_ptr(), self.length) }
+    }
+}
+
+impl<T, G> core::ops::DerefMut for ChillVec<T, G> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.data.as_ptr(), self.length) }
+    }
+}
+
+impl<T, G: GrowthStrategy> FromIterator<T> for ChillVec<T, G> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::with_growth();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T, G: GrowthStrategy> Extend<T> for ChillVec<T, G> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.spec_extend(iter.into_iter());
+    }
+}
+
+impl<'a, T: Copy + 'a, G: GrowthStrategy> Extend<&'a T> for ChillVec<T, G> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.spec_extend(iter.into_iter());
+    }
+}
+
+impl<T, G: GrowthStrategy> ChillVec<T, G> {
+    /// Pushes everything from an iterator whose size_hint may be wrong
+    #[inline]
+    fn extend_desugared<I: Iterator<Item = T>>(&mut self, mut iter: I) {
+        while let Some(item) = iter.next() {
+            if self.length == self.capacity {
+                // The lower bound is all we can trust, and it's only a guess for what's left
+                let (lower, _) = iter.size_hint();
+                self.reserve(lower.saturating_add(1));
+            }
+            unsafe {
+                ptr::write(self.data.as_ptr().add(self.length), item);
+            }
+            self.length += 1;
+        }
+    }
+
+    /// Pushes everything from an iterator that yields exactly as many items as the upper bound of
+    /// its size_hint, so it can reserve once and never check capacity again
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn extend_trusted<I: core::iter::TrustedLen<Item = T>>(&mut self, iter: I) {
+        match iter.size_hint() {
+            (_, Some(additional)) => {
+                self.reserve(additional);
+                let data = self.data.as_ptr();
+                for item in iter {
+                    unsafe {
+                        ptr::write(data.add(self.length), item);
+                    }
+                    // Bumped one at a time so a panicking iterator leaves only initialized elements
+                    self.length += 1;
+                }
+            }
+            // More than usize::MAX items, which we couldn't hold anyway
+            (_, None) => self.extend_desugared(iter),
+        }
+    }
+
+    /// Appends a whole slice with one reservation and a memcpy
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn extend_copied(&mut self, items: &[T])
+    where
+        T: Copy,
+    {
+        self.reserve(items.len());
+        unsafe {
+            ptr::copy_nonoverlapping(
+                items.as_ptr(),
+                self.data.as_ptr().add(self.length),
+                items.len(),
+            );
+        }
+        self.length += items.len();
+    }
+}
+
+// The internals of Extend. On nightly this is specialized so that iterators which know their exact
+// length, and slices of Copy types, skip the capacity check on every item. On stable everything
+// takes the general path.
+trait SpecExtend<T, I> {
+    fn spec_extend(&mut self, iter: I);
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T, I: Iterator<Item = T>, G: GrowthStrategy> SpecExtend<T, I> for ChillVec<T, G> {
+    #[inline]
+    fn spec_extend(&mut self, iter: I) {
+        self.extend_desugared(iter);
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, I: Iterator<Item = T>, G: GrowthStrategy> SpecExtend<T, I> for ChillVec<T, G> {
+    #[inline]
+    default fn spec_extend(&mut self, iter: I) {
+        self.extend_desugared(iter);
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, I, G> SpecExtend<T, I> for ChillVec<T, G>
+where
+    I: core::iter::TrustedLen<Item = T>,
+    G: GrowthStrategy,
+{
+    #[inline]
+    fn spec_extend(&mut self, iter: I) {
+        self.extend_trusted(iter);
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<'a, T, I, G> SpecExtend<&'a T, I> for ChillVec<T, G>
+where
+    T: Copy + 'a,
+    I: Iterator<Item = &'a T>,
+    G: GrowthStrategy,
+{
+    #[inline]
+    fn spec_extend(&mut self, iter: I) {
+        self.extend_desugared(iter.copied());
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, T, I, G> SpecExtend<&'a T, I> for ChillVec<T, G>
+where
+    T: Copy + 'a,
+    I: Iterator<Item = &'a T>,
+    G: GrowthStrategy,
+{
+    #[inline]
+    default fn spec_extend(&mut self, iter: I) {
+        self.spec_extend(iter.copied());
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, T: Copy + 'a, G: GrowthStrategy> SpecExtend<&'a T, slice::Iter<'a, T>> for ChillVec<T, G> {
+    #[inline]
+    fn spec_extend(&mut self, iter: slice::Iter<'a, T>) {
+        self.extend_copied(iter.as_slice());
+    }
+}
+
+// Vec allocates from the global allocator with the same Layout we use (capacity * size_of::<T>()
+// bytes aligned to T), so buffers can be handed across in both directions without copying
+
+impl<T> From<Vec<T>> for ChillVec<T> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        assert!(size_of::<T>() > 0);
+        let mut vec = mem::ManuallyDrop::new(vec);
+        Self {
+            // Vec's pointer is never null, it's dangling when nothing is allocated
+            data: unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) },
+            length: vec.len(),
+            capacity: vec.capacity(),
+            marker: PhantomData,
+            growth: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ChillVec<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        let array = mem::ManuallyDrop::new(array);
+        let mut vec = Self::with_capacity(N);
+        unsafe {
+            ptr::copy_nonoverlapping(array.as_ptr(), vec.data.as_ptr(), N);
+        }
+        vec.length = N;
+        vec
+    }
+}
+
+impl<T, G> From<ChillVec<T, G>> for Vec<T> {
+    #[inline]
+    fn from(vec: ChillVec<T, G>) -> Self {
+        let vec = mem::ManuallyDrop::new(vec);
+        unsafe { Vec::from_raw_parts(vec.data.as_ptr(), vec.length, vec.capacity) }
+    }
+}
+
+impl<T, U, G, H> PartialEq<ChillVec<U, H>> for ChillVec<T, G>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &ChillVec<U, H>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, U, G> PartialEq<[U]> for ChillVec<T, G>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<'a, T, U, G> PartialEq<&'a [U]> for ChillVec<T, G>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &&'a [U]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, U, G, const N: usize> PartialEq<[U; N]> for ChillVec<T, G>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U; N]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, U, G> PartialEq<Vec<U>> for ChillVec<T, G>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, U, G> PartialEq<ChillVec<U, G>> for Vec<T>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &ChillVec<U, G>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: Eq, G> Eq for ChillVec<T, G> {}
+
+impl<T: PartialOrd, G> PartialOrd for ChillVec<T, G> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self[..].partial_cmp(&other[..])
+    }
+}
+
+impl<T: Ord, G> Ord for ChillVec<T, G> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self[..].cmp(&other[..])
+    }
+}
+
+// Must agree with the Hash impl for slices, which the Borrow impls promise
+impl<T: Hash, G> Hash for ChillVec<T, G> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self[..].hash(state)
+    }
+}
+
+impl<T, G> AsRef<[T]> for ChillVec<T, G> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, G> AsMut<[T]> for ChillVec<T, G> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, G> Borrow<[T]> for ChillVec<T, G> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, G> BorrowMut<[T]> for ChillVec<T, G> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+/// Appends everything written to the end of the vector. Writes never fail; running out of memory
+/// aborts, as it does everywhere else in this crate.
+#[cfg(feature = "std")]
+impl<G: GrowthStrategy> std::io::Write for ChillVec<u8, G> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// An iterator over the elements removed by `ChillVec::drain`
+pub struct ChillDrain<'a, T: 'a, G: 'a = Doubling> {
+    vec: &'a mut ChillVec<T, G>,
+    // The not-yet-yielded elements are those in front..back
+    front: usize,
+    back: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<'a, T, G> Iterator for ChillDrain<'a, T, G> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        let item = unsafe { ptr::read(self.vec.data.as_ptr().add(self.front)) };
+        self.front += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T, G> DoubleEndedIterator for ChillDrain<'a, T, G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(unsafe { ptr::read(self.vec.data.as_ptr().add(self.back)) })
+    }
+}
+
+impl<'a, T, G> ExactSizeIterator for ChillDrain<'a, T, G> {}
+
+impl<'a, T, G> FusedIterator for ChillDrain<'a, T, G> {}
+
+impl<'a, T, G> ChillDrain<'a, T, G> {
+    /// Moves items into the gap between the vector's length and the tail,
+    /// returning false if the items ran out before the gap was filled
+    fn fill_gap<I: Iterator<Item = T>>(&mut self, items: &mut I) -> bool {
+        let vec = &mut *self.vec;
+        while vec.length < self.tail_start {
+            match items.next() {
+                Some(item) => unsafe {
+                    ptr::write(vec.data.as_ptr().add(vec.length), item);
+                    vec.length += 1;
+                },
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+impl<'a, T, G> Drop for ChillDrain<'a, T, G> {
+    fn drop(&mut self) {
+        unsafe {
+            let data = self.vec.data.as_ptr();
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                data.add(self.front),
+                self.back - self.front,
+            ));
+
+            // Slide the tail down to close the gap
+            let start = self.vec.length;
+            if self.tail_start != start {
+                ptr::copy(data.add(self.tail_start), data.add(start), self.tail_len);
+            }
+            self.vec.length = start + self.tail_len;
+        }
+    }
+}
+
+/// An iterator over the elements removed by `ChillVec::splice`
+pub struct ChillSplice<'a, I: Iterator + 'a, G: GrowthStrategy + 'a = Doubling> {
+    drain: ChillDrain<'a, I::Item, G>,
+    replace_with: I,
+}
+
+impl<'a, I: Iterator, G: GrowthStrategy> Iterator for ChillSplice<'a, I, G> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.drain.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<'a, I: Iterator, G: GrowthStrategy> DoubleEndedIterator for ChillSplice<'a, I, G> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<'a, I: Iterator, G: GrowthStrategy> ExactSizeIterator for ChillSplice<'a, I, G> {}
+
+impl<'a, I: Iterator, G: GrowthStrategy> FusedIterator for ChillSplice<'a, I, G> {}
+
+impl<'a, I: Iterator, G: GrowthStrategy> Drop for ChillSplice<'a, I, G> {
+    fn drop(&mut self) {
+        // Drop whatever the caller didn't take, the gap is now everything from the vector's
+        // length up to the tail
+        self.drain.by_ref().for_each(drop);
+
+        if !self.drain.fill_gap(&mut self.replace_with) {
+            // Dropping the drain closes what's left of the gap
+            return;
+        }
+
+        // Stash the remaining replacements so we know how far to move the tail
+        let rest: ChillVec<I::Item, G> = self.replace_with.by_ref().collect();
+        if rest.is_empty() {
+            return;
+        }
+
+        let drain = &mut self.drain;
+        let new_tail_start = drain.tail_start + rest.len();
+        // The gap has been filled, so the vector's length is the tail's start
+        drain.vec.reserve(rest.len() + drain.tail_len);
+        unsafe {
+            let data = drain.vec.data.as_ptr();
+            ptr::copy(
+                data.add(drain.tail_start),
+                data.add(new_tail_start),
+                drain.tail_len,
+            );
+        }
+        drain.tail_start = new_tail_start;
+
+        drain.fill_gap(&mut rest.into_iter());
+    }
+}
+
+/// An iterator over the elements removed by `ChillVec::extract_if`
+pub struct ChillExtractIf<'a, T: 'a, F, G: 'a = Doubling>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut ChillVec<T, G>,
+    // The next element to pass to filter
+    index: usize,
+    // How many elements have been yielded, which is also how far the kept ones are moved down
+    removed: usize,
+    old_len: usize,
+    filter: F,
+}
+
+impl<'a, T, F, G> Iterator for ChillExtractIf<'a, T, F, G>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe {
+            let data = self.vec.data.as_ptr();
+            while self.index < self.old_len {
+                let item = data.add(self.index);
+                let extract = (self.filter)(&mut *item);
+                // Incremented after the call, so if filter panics Drop keeps this element
+                self.index += 1;
+                if extract {
+                    self.removed += 1;
+                    return Some(ptr::read(item));
+                } else if self.removed > 0 {
+                    ptr::copy_nonoverlapping(item, item.sub(self.removed), 1);
+                }
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.index))
+    }
+}
+
+impl<'a, T, F, G> FusedIterator for ChillExtractIf<'a, T, F, G> where F: FnMut(&mut T) -> bool {}
+
+impl<'a, T, F, G> Drop for ChillExtractIf<'a, T, F, G>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        unsafe {
+            // Slide the unvisited elements down to close the gap
+            let data = self.vec.data.as_ptr();
+            if self.removed > 0 && self.index < self.old_len {
+                ptr::copy(
+                    data.add(self.index),
+                    data.add(self.index - self.removed),
+                    self.old_len - self.index,
+                );
+            }
+        }
+        self.vec.length = self.old_len - self.removed;
+    }
+}
+
+/// An iterator that moves elements out of a `ChillVec`
+pub struct ChillIntoIter<T> {
+    buf: NonNull<T>,
+    cap: usize,
+    // The not-yet-yielded elements are those in start..end
+    start: usize,
+    end: usize,
+    marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for ChillIntoIter<T> {}
+unsafe impl<T: Sync> Sync for ChillIntoIter<T> {}
+
+impl<T> Iterator for ChillIntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        let item = unsafe { ptr::read(self.buf.as_ptr().add(self.start)) };
+        self.start += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for ChillIntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { ptr::read(self.buf.as_ptr().add(self.end)) })
+    }
+}
+
+impl<T> ExactSizeIterator for ChillIntoIter<T> {}
+
+impl<T> FusedIterator for ChillIntoIter<T> {}
+
+#[cfg(not(feature = "nightly"))]
+impl<T> Drop for ChillIntoIter<T> {
+    fn drop(&mut self) {
+        unsafe { self.drop_remaining() }
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T> Drop for ChillIntoIter<T> {
+    fn drop(&mut self) {
+        unsafe { self.drop_remaining() }
+    }
+}
+
+impl<T> ChillIntoIter<T> {
+    /// Drops the elements that haven't been yielded and frees the allocation
+    unsafe fn drop_remaining(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.buf.as_ptr().add(self.start),
+                self.end - self.start,
+            ));
+        }
+        // Hand the allocation back to an empty ChillVec so it is freed the usual way
+        drop(ChillVec::<T> {
+            data: self.buf,
+            length: 0,
+            capacity: self.cap,
+            marker: PhantomData,
+            growth: PhantomData,
+        });
+    }
+}
+
+impl<T, G> IntoIterator for ChillVec<T, G> {
+    type Item = T;
+    type IntoIter = ChillIntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> ChillIntoIter<T> {
+        let iter = ChillIntoIter {
+            buf: self.data,
+            cap: self.capacity,
+            start: 0,
+            end: self.length,
+            marker: PhantomData,
+        };
+        mem::forget(self);
+        iter
+    }
+}
+
+impl<'a, T, G> IntoIterator for &'a ChillVec<T, G> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, G> IntoIterator for &'a mut ChillVec<T, G> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::ChillVec;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use core::mem::size_of;
+    use growth::GrowthStrategy;
+    use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    impl<T: Serialize, G> Serialize for ChillVec<T, G> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    struct ChillVecVisitor<T, G>(PhantomData<(T, G)>);
+
+    impl<'de, T, G> Visitor<'de> for ChillVecVisitor<T, G>
+    where
+        T: Deserialize<'de>,
+        G: GrowthStrategy,
+    {
+        type Value = ChillVec<T, G>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            // The size hint comes from the input, so don't let it talk us into a huge allocation
+            const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+            let max_elements = MAX_PREALLOC_BYTES / size_of::<T>().max(1);
+            let capacity = seq.size_hint().unwrap_or(0).min(max_elements);
+
+            let mut vec = ChillVec::with_capacity_and_growth(capacity);
+            while let Some(item) = seq.next_element()? {
+                vec.push(item);
+            }
+            Ok(vec)
+        }
+    }
+
+    impl<'de, T, G> Deserialize<'de> for ChillVec<T, G>
+    where
+        T: Deserialize<'de>,
+        G: GrowthStrategy,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(ChillVecVisitor(PhantomData))
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon_impls {
+    use super::ChillVec;
+    use alloc::collections::LinkedList;
+    use alloc::vec::Vec;
+    use growth::GrowthStrategy;
+    use rayon::iter::{
+        FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator,
+    };
+
+    impl<T: Send, G> IntoParallelIterator for ChillVec<T, G> {
+        type Iter = rayon::vec::IntoIter<T>;
+        type Item = T;
+
+        fn into_par_iter(self) -> Self::Iter {
+            // Handing the buffer to a Vec is free, and rayon already knows how to split one up
+            Vec::from(self).into_par_iter()
+        }
+    }
+
+    impl<'a, T: Sync + 'a, G: 'a> IntoParallelIterator for &'a ChillVec<T, G> {
+        type Iter = rayon::slice::Iter<'a, T>;
+        type Item = &'a T;
+
+        fn into_par_iter(self) -> Self::Iter {
+            self[..].into_par_iter()
+        }
+    }
+
+    impl<'a, T: Send + 'a, G: 'a> IntoParallelIterator for &'a mut ChillVec<T, G> {
+        type Iter = rayon::slice::IterMut<'a, T>;
+        type Item = &'a mut T;
+
+        fn into_par_iter(self) -> Self::Iter {
+            (&mut self[..]).into_par_iter()
+        }
+    }
+
+    impl<T: Send, G: GrowthStrategy> ParallelExtend<T> for ChillVec<T, G> {
+        fn par_extend<I>(&mut self, par_iter: I)
+        where
+            I: IntoParallelIterator<Item = T>,
+        {
+            // Every rayon job fills its own ChillVec, then they're stitched together after one
+            // reservation for the total length
+            let chunks = par_iter
+                .into_par_iter()
+                .fold(ChillVec::<T, G>::with_growth, |mut vec, item| {
+                    vec.push(item);
+                    vec
+                })
+                .map(|vec| {
+                    let mut list = LinkedList::new();
+                    list.push_back(vec);
+                    list
+                })
+                .reduce(LinkedList::new, |mut a, mut b| {
+                    a.append(&mut b);
+                    a
+                });
+
+            self.reserve(chunks.iter().map(|chunk| chunk.len()).sum());
+            for mut chunk in chunks {
+                self.append(&mut chunk);
+            }
+        }
+    }
+
+    impl<T: Send, G: GrowthStrategy> FromParallelIterator<T> for ChillVec<T, G> {
+        fn from_par_iter<I>(par_iter: I) -> Self
+        where
+            I: IntoParallelIterator<Item = T>,
+        {
+            let mut vec = ChillVec::with_growth();
+            vec.par_extend(par_iter);
+            vec
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use super::ChillVec;
+    use arbitrary::{Arbitrary, Result, Unstructured};
+    use growth::GrowthStrategy;
+
+    impl<'a, T, G> Arbitrary<'a> for ChillVec<T, G>
+    where
+        T: Arbitrary<'a>,
+        G: GrowthStrategy,
+    {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            u.arbitrary_iter()?.collect()
+        }
+
+        fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+            u.arbitrary_take_rest_iter()?.collect()
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::prelude::v1::*;
 
     #[test]
     fn push() {
@@ -296,7 +2125,7 @@ mod tests {
 
         vec.push(1337);
         assert_eq!(vec.len(), 1);
-        assert_eq!(vec.get(0), Some(&1337));
+        assert_eq!(vec.first(), Some(&1337));
 
         vec.push(1);
         assert_eq!(vec.len(), 2);
@@ -353,4 +2182,802 @@ mod tests {
 
         assert!(v.capacity() >= 17)
     }
+
+    #[test]
+    fn drain() {
+        let mut vec = ChillVec::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+
+        {
+            let mut drain = vec.drain(1..=3).unwrap();
+            assert_eq!(drain.len(), 3);
+            assert_eq!(drain.next(), Some(2));
+            assert_eq!(drain.next_back(), Some(4));
+        }
+        assert_eq!(vec[..], [1, 5, 6]);
+
+        let (start, end) = (2, 1);
+        assert!(vec.drain(start..end).is_none());
+        assert!(vec.drain(..4).is_none());
+        assert_eq!(vec.drain(3..).map(|d| d.count()), Some(0));
+
+        assert_eq!(vec.drain(..).unwrap().collect::<Vec<_>>(), [1, 5, 6]);
+        assert_eq!(vec.len(), 0);
+    }
+
+    #[test]
+    fn retain() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut vec = ChillVec::new();
+        for i in 0..6 {
+            vec.push((i, counter.clone()));
+        }
+
+        vec.retain(|&(i, _)| i % 3 != 0);
+        assert_eq!(
+            vec.iter().map(|&(i, _)| i).collect::<Vec<_>>(),
+            [1, 2, 4, 5]
+        );
+        // The rejected elements were dropped
+        assert_eq!(Rc::strong_count(&counter), 5);
+
+        vec.retain_mut(|(i, _)| {
+            *i *= 10;
+            *i > 20
+        });
+        assert_eq!(vec.iter().map(|&(i, _)| i).collect::<Vec<_>>(), [40, 50]);
+        assert_eq!(Rc::strong_count(&counter), 3);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut vec = ChillVec::<i32>::new();
+        vec.dedup();
+        assert_eq!(vec.len(), 0);
+
+        vec.push(7);
+        vec.dedup();
+        assert_eq!(vec[..], [7]);
+
+        vec.extend_from_slice(&[7, 7, 8, 7]);
+        vec.dedup();
+        assert_eq!(vec[..], [7, 8, 7]);
+
+        let mut words = ChillVec::new();
+        words.push(String::from("foo"));
+        words.push(String::from("FOO"));
+        words.push(String::from("bar"));
+        words.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(words[..], ["foo", "bar"]);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut vec = ChillVec::new();
+        vec.extend_from_slice(&[1, 2, 3, 4]);
+
+        let empty = vec.split_off(4).unwrap();
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.capacity(), 0);
+
+        let tail = vec.split_off(2).unwrap();
+        assert_eq!(vec[..], [1, 2]);
+        assert_eq!(tail[..], [3, 4]);
+
+        let all = vec.split_off(0).unwrap();
+        assert_eq!(vec.len(), 0);
+        assert_eq!(all[..], [1, 2]);
+
+        assert!(vec.split_off(1).is_none());
+    }
+
+    #[test]
+    fn collect() {
+        let vec: ChillVec<u32> = (0..10).collect();
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(vec[..], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        // No size hint, so this has to grow as it goes
+        let vec: ChillVec<u32> = (0..100).filter(|x| x % 10 == 0).collect();
+        assert_eq!(vec[..], [0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
+
+        let empty: ChillVec<u32> = None.into_iter().collect();
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    #[test]
+    fn into_iter() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut vec = ChillVec::new();
+        for i in 0..4 {
+            vec.push((i, counter.clone()));
+        }
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        let (first, rc) = iter.next().unwrap();
+        assert_eq!(first, 0);
+        drop(rc);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+
+        // Dropping the iterator drops the elements it didn't yield
+        drop(iter);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let mut vec = ChillVec::new();
+        vec.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(vec.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+
+        assert_eq!(ChillVec::<u8>::new().into_iter().next(), None);
+    }
+
+    #[test]
+    fn std_vec_conversions() {
+        let mut std_vec = Vec::with_capacity(10);
+        std_vec.extend_from_slice(&[String::from("a"), String::from("b")]);
+        let ptr = std_vec.as_ptr();
+
+        let mut vec = ChillVec::from(std_vec);
+        assert_eq!(vec.as_ptr(), ptr);
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(vec[..], ["a", "b"]);
+
+        vec.push(String::from("c"));
+        let std_vec = Vec::from(vec);
+        assert_eq!(std_vec.as_ptr(), ptr);
+        assert_eq!(std_vec, ["a", "b", "c"]);
+
+        let empty: Vec<u64> = ChillVec::new().into();
+        assert_eq!(empty.capacity(), 0);
+        let empty = ChillVec::from(empty);
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    #[test]
+    fn extend_from_slice_clone() {
+        let words = [String::from("foo"), String::from("bar")];
+        let mut vec = ChillVec::new();
+        vec.extend_from_slice(&words);
+        vec.extend_from_slice(&words[1..]);
+        vec.extend_from_slice(&[]);
+        assert_eq!(vec[..], ["foo", "bar", "bar"]);
+        // The clones own their own buffers
+        assert_ne!(vec[0].as_ptr(), words[0].as_ptr());
+    }
+
+    #[test]
+    fn try_reserve() {
+        let mut vec = ChillVec::<u64>::new();
+        vec.try_reserve(0).unwrap();
+        assert_eq!(vec.capacity(), 0);
+
+        vec.push(1);
+        vec.try_reserve(10).unwrap();
+        assert!(vec.capacity() >= 11);
+        assert_eq!(vec[..], [1]);
+
+        // Overflows usize before the allocator is consulted
+        assert_eq!(vec.try_reserve(usize::MAX), Err(AllocError));
+        // Fits in a usize but not in isize::MAX bytes
+        assert_eq!(vec.try_reserve(usize::MAX / 8), Err(AllocError));
+
+        // The failed attempts left the vector untouched
+        assert!(vec.capacity() >= 11);
+        assert_eq!(vec[..], [1]);
+    }
+
+    #[test]
+    fn try_push() {
+        let mut vec = ChillVec::new();
+        for i in 0..20 {
+            assert_eq!(vec.try_push(i), Ok(()));
+        }
+        assert_eq!(vec.len(), 20);
+        assert!(vec.iter().copied().eq(0..20));
+
+        // Pretend we've filled an enormous buffer so the next growth can't be satisfied
+        let mut vec = ChillVec::<u64>::new();
+        vec.capacity = usize::MAX / 16;
+        vec.length = vec.capacity;
+        assert_eq!(vec.try_push(7), Err(7));
+        vec.length = 0;
+        vec.capacity = 0;
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let mut vec = ChillVec::new();
+        vec.extend_from_slice(&[1u32, 2, 3]);
+
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, "[1,2,3]");
+
+        let back: ChillVec<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back[..], [1, 2, 3]);
+
+        let empty: ChillVec<String> = serde_json::from_str("[]").unwrap();
+        assert_eq!(empty.len(), 0);
+
+        assert!(serde_json::from_str::<ChillVec<u32>>("[1,\"a\"]").is_err());
+    }
+
+    #[test]
+    fn resize() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut vec = ChillVec::new();
+        vec.resize(4, counter.clone());
+        assert_eq!(vec.len(), 4);
+        assert_eq!(Rc::strong_count(&counter), 5);
+
+        vec.resize(1, counter.clone());
+        assert_eq!(vec.len(), 1);
+        assert_eq!(Rc::strong_count(&counter), 2);
+
+        vec.resize_with(3, || counter.clone());
+        assert_eq!(vec.len(), 3);
+        assert_eq!(Rc::strong_count(&counter), 4);
+
+        vec.resize_with(0, || unreachable!());
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn compare_and_hash() {
+        use std::collections::HashMap;
+
+        let a: ChillVec<i32> = (1..4).collect();
+        let b: ChillVec<i32> = (1..4).collect();
+        let c: ChillVec<i32> = (1..5).collect();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(a, &[1, 2, 3][..]);
+        assert_eq!(a, vec![1, 2, 3]);
+        assert_eq!(vec![1, 2, 3], a);
+        assert!(a < c);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+
+        let mut map = HashMap::new();
+        map.insert(a, "abc");
+        assert_eq!(map.get(&b), Some(&"abc"));
+        assert_eq!(map.get(&c), None);
+        assert_eq!(map.get(&[1, 2, 3][..]), Some(&"abc"));
+    }
+
+    #[test]
+    fn into_boxed_slice() {
+        let mut vec = ChillVec::with_capacity(16);
+        vec.push(String::from("a"));
+        vec.push(String::from("b"));
+        let boxed = vec.into_boxed_slice();
+        assert_eq!(*boxed, ["a", "b"]);
+
+        let boxed = ChillVec::<u8>::with_capacity(16).into_boxed_slice();
+        assert!(boxed.is_empty());
+        let boxed = ChillVec::<u8>::new().into_boxed_slice();
+        assert!(boxed.is_empty());
+    }
+
+    #[test]
+    fn leak() {
+        let mut vec = ChillVec::new();
+        vec.push(String::from("static"));
+        let leaked: &'static mut [String] = vec.leak();
+        leaked[0].push('!');
+        assert_eq!(leaked, ["static!"]);
+
+        let empty: &'static mut [u8] = ChillVec::new().leak();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn raw_parts() {
+        let mut vec = ChillVec::with_capacity(4);
+        vec.push(String::from("a"));
+        let ptr = vec.as_mut_ptr();
+        assert_eq!(vec.as_ptr(), ptr as *const String);
+
+        let (raw, len, cap) = vec.into_raw_parts();
+        assert_eq!((raw, len, cap), (ptr, 1, 4));
+        let mut vec = unsafe { ChillVec::from_raw_parts(raw, len, cap) };
+        vec.push(String::from("b"));
+        assert_eq!(vec[..], ["a", "b"]);
+
+        // Buffers from std's Vec can be adopted too
+        let mut std_vec = vec![1u16, 2, 3];
+        let (ptr, len, cap) = (std_vec.as_mut_ptr(), std_vec.len(), std_vec.capacity());
+        mem::forget(std_vec);
+        let vec = unsafe { ChillVec::from_raw_parts(ptr, len, cap) };
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(vec.capacity(), cap);
+        assert_eq!(vec.as_ptr(), ptr as *const u16);
+    }
+
+    #[test]
+    fn splice() {
+        let mut vec: ChillVec<String> =
+            ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+
+        // Fewer replacements than removed elements
+        let removed: Vec<String> = vec.splice(1..3, Some(String::from("x"))).unwrap().collect();
+        assert_eq!(removed, ["b", "c"]);
+        assert_eq!(vec, ["a", "x", "d"]);
+
+        // More replacements than removed elements, and the removed ones are never consumed
+        let more = ["1", "2", "3", "4"].iter().map(|s| s.to_string());
+        drop(vec.splice(1..2, more).unwrap());
+        assert_eq!(vec, ["a", "1", "2", "3", "4", "d"]);
+
+        // A pure insertion at the end
+        drop(vec.splice(6.., Some(String::from("z"))));
+        assert_eq!(vec, ["a", "1", "2", "3", "4", "d", "z"]);
+
+        assert!(vec.splice(5..8, None).is_none());
+        assert_eq!(vec.len(), 7);
+
+        drop(vec.splice(.., None));
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn append() {
+        let mut a = ChillVec::new();
+        a.push(String::from("a"));
+        let mut b = ChillVec::with_capacity(8);
+        b.push(String::from("b"));
+        b.push(String::from("c"));
+
+        a.append(&mut b);
+        assert_eq!(a, ["a", "b", "c"]);
+        assert!(b.is_empty());
+        assert_eq!(b.capacity(), 8);
+
+        a.append(&mut b);
+        assert_eq!(a.len(), 3);
+
+        let mut empty = ChillVec::new();
+        empty.append(&mut a);
+        assert_eq!(empty, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn spare_capacity() {
+        let mut vec = ChillVec::new();
+        assert!(vec.spare_capacity_mut().is_empty());
+
+        vec.reserve(8);
+        vec.push(String::from("a"));
+        let capacity = vec.capacity();
+        let spare = vec.spare_capacity_mut();
+        assert_eq!(spare.len(), capacity - 1);
+        spare[0] = MaybeUninit::new(String::from("b"));
+        unsafe { vec.set_len(2) };
+        assert_eq!(vec, ["a", "b"]);
+        assert_eq!(vec.spare_capacity_mut().len(), capacity - 2);
+    }
+
+    #[test]
+    fn amortized_growth() {
+        let mut vec = ChillVec::new();
+        let mut reallocations = 0;
+        for i in 0..1000 {
+            let capacity = vec.capacity();
+            vec.reserve(1);
+            if vec.capacity() != capacity {
+                reallocations += 1;
+                assert!(vec.capacity() >= 2 * capacity);
+            }
+            vec.push(i);
+        }
+        assert!(reallocations <= 10);
+
+        let mut vec = ChillVec::<u8>::new();
+        vec.reserve_exact(3);
+        assert_eq!(vec.capacity(), 3);
+        vec.extend_from_slice(&[1, 2, 3]);
+        vec.reserve_exact(1);
+        assert_eq!(vec.capacity(), 4);
+        vec.reserve_exact(0);
+        assert_eq!(vec.capacity(), 4);
+    }
+
+    #[test]
+    fn shrink() {
+        let mut vec = ChillVec::<u32>::with_capacity(64);
+        vec.extend_from_slice(&[1, 2, 3, 4]);
+
+        vec.shrink_to(100);
+        assert_eq!(vec.capacity(), 64);
+        vec.shrink_to(16);
+        assert_eq!(vec.capacity(), 16);
+        vec.shrink_to(2);
+        assert_eq!(vec.capacity(), 4);
+        assert_eq!(vec, [1, 2, 3, 4]);
+
+        vec.push(5);
+        vec.shrink_to_fit();
+        assert_eq!(vec.capacity(), 5);
+        assert_eq!(vec, [1, 2, 3, 4, 5]);
+
+        let mut empty = ChillVec::<u32>::with_capacity(8);
+        empty.shrink_to_fit();
+        assert_eq!(empty.capacity(), 0);
+        empty.push(1);
+        assert_eq!(empty, [1]);
+    }
+
+    #[test]
+    fn drops_elements() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut vec = ChillVec::new();
+        for _ in 0..8 {
+            vec.push(counter.clone());
+        }
+        assert_eq!(Rc::strong_count(&counter), 9);
+
+        vec.truncate(5);
+        assert_eq!(vec.len(), 5);
+        assert_eq!(Rc::strong_count(&counter), 6);
+
+        // Overwriting an element drops the old one
+        vec[0] = Rc::new(());
+        assert_eq!(Rc::strong_count(&counter), 5);
+
+        let capacity = vec.capacity();
+        vec.clear();
+        assert!(vec.is_empty());
+        assert_eq!(vec.capacity(), capacity);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        vec.push(counter.clone());
+        vec.push(counter.clone());
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn clone() {
+        let mut vec = ChillVec::new();
+        vec.push(String::from("hello"));
+        vec.push(String::from("world"));
+
+        let cloned = vec.clone();
+        assert_eq!(cloned, vec);
+        assert_ne!(cloned[0].as_ptr(), vec[0].as_ptr());
+        drop(vec);
+        assert_eq!(cloned, ["hello", "world"]);
+
+        let mut target = ChillVec::with_capacity(10);
+        target.push(String::with_capacity(32));
+        let reused = target[0].as_ptr();
+        target.clone_from(&cloned);
+        assert_eq!(target, ["hello", "world"]);
+        assert_eq!(target[0].as_ptr(), reused);
+        assert_eq!(target.capacity(), 10);
+
+        target.clone_from(&ChillVec::new());
+        assert!(target.is_empty());
+
+        let empty = ChillVec::<u8>::new().clone();
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    #[test]
+    fn chillvec_macro() {
+        let empty: ChillVec<u8> = chillvec![];
+        assert_eq!(empty.capacity(), 0);
+
+        let words = chillvec![String::from("a"), String::from("b"),];
+        assert_eq!(words, ["a", "b"]);
+        assert_eq!(words.capacity(), 2);
+
+        let repeated = chillvec![String::from("x"); 3];
+        assert_eq!(repeated, ["x", "x", "x"]);
+        assert_eq!(repeated.capacity(), 3);
+
+        let none: ChillVec<String> = chillvec![String::from("x"); 0];
+        assert!(none.is_empty());
+
+        assert_eq!(ChillVec::from([1, 2]), [1, 2]);
+    }
+
+    #[test]
+    fn get_many_mut() {
+        let mut vec = chillvec![String::from("a"), String::from("b"), String::from("c")];
+
+        {
+            let [c, a] = vec.get_many_mut([2, 0]).unwrap();
+            c.push('!');
+            a.push('?');
+        }
+        assert_eq!(vec, ["a?", "b", "c!"]);
+
+        assert!(vec.get_many_mut([]).is_some());
+        assert!(vec.get_many_mut([0, 1, 0]).is_none());
+        assert!(vec.get_many_mut([3]).is_none());
+        assert!(vec.get_many_mut([usize::MAX, 0]).is_none());
+    }
+
+    #[test]
+    fn binary_search() {
+        let empty = ChillVec::<i32>::new();
+        assert_eq!(empty.binary_search(&1), Err(0));
+        assert_eq!(empty.partition_point(|_| true), 0);
+        assert!(empty.is_sorted());
+
+        let vec = chillvec![1, 3, 3, 5, 8, 13];
+        assert!(vec.is_sorted());
+        for x in vec.iter() {
+            assert_eq!(vec[vec.binary_search(x).unwrap()], *x);
+            let first = vec.iter().position(|y| y == x).unwrap();
+            assert_eq!(vec.partition_point(|y| y < x), first);
+        }
+        assert_eq!(vec.binary_search(&0), Err(0));
+        assert_eq!(vec.binary_search(&4), Err(3));
+        assert_eq!(vec.binary_search(&100), Err(6));
+        assert_eq!(vec.binary_search_by(|x| x.cmp(&8)), Ok(4));
+        assert_eq!(vec.partition_point(|x| *x < 100), 6);
+
+        assert!(!chillvec![2, 1].is_sorted());
+        assert!(!chillvec![1.0, f64::NAN].is_sorted());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn rayon() {
+        use rayon::prelude::*;
+
+        let mut vec: ChillVec<u64> = (0..10_000u64).into_par_iter().collect();
+        assert!(vec.iter().copied().eq(0..10_000));
+
+        vec.par_iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(vec.par_iter().sum::<u64>(), 2 * (0..10_000).sum::<u64>());
+
+        vec.par_extend((0..10u64).into_par_iter().filter(|x| x % 2 == 0));
+        assert_eq!(vec.len(), 10_005);
+        assert_eq!(vec[10_000..], [0, 2, 4, 6, 8]);
+
+        let strings: ChillVec<String> = vec.into_par_iter().map(|x| x.to_string()).collect();
+        assert_eq!(strings[1], "2");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_write() {
+        use std::io::Write;
+
+        let mut vec = ChillVec::new();
+        let two = "two";
+        write!(vec, "{}-{}", 1, two).unwrap();
+        vec.write_all(b"!").unwrap();
+        assert_eq!(vec.write(b"abc").unwrap(), 3);
+        vec.flush().unwrap();
+        assert_eq!(vec, *b"1-two!abc");
+    }
+
+    #[test]
+    fn const_new() {
+        const EMPTY: ChillVec<String> = ChillVec::new();
+        static STATIC: ChillVec<u64> = ChillVec::EMPTY;
+
+        let mut vec = EMPTY;
+        assert_eq!(vec.capacity(), 0);
+        vec.push(String::from("a"));
+        assert_eq!(vec, ["a"]);
+        assert!(STATIC.is_empty());
+    }
+
+    #[test]
+    fn into_iter_double_ended() {
+        fn assert_traits<I: DoubleEndedIterator + ExactSizeIterator + FusedIterator>(_: &I) {}
+
+        let vec = chillvec![String::from("a"), String::from("b"), String::from("c")];
+        let mut iter = vec.into_iter();
+        assert_traits(&iter);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back().unwrap(), "c");
+        assert_eq!(iter.next().unwrap(), "a");
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back().unwrap(), "b");
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+
+        let vec = chillvec![1, 2, 3];
+        assert_eq!(vec.into_iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+
+        let mut vec = chillvec![1, 2, 3];
+        assert_traits(&vec.drain(..).unwrap());
+        assert_traits(&vec.splice(.., None).unwrap());
+    }
+
+    #[test]
+    fn swap() {
+        let mut vec = chillvec![String::from("a"), String::from("b")];
+        assert!(vec.swap(0, 1));
+        assert_eq!(vec, ["b", "a"]);
+        assert!(vec.swap(1, 1));
+        assert_eq!(vec, ["b", "a"]);
+        assert!(!vec.swap(2, 0));
+        assert!(!vec.swap(0, usize::MAX));
+        assert_eq!(vec, ["b", "a"]);
+        assert!(!ChillVec::<u8>::new().swap(0, 0));
+    }
+
+    #[test]
+    fn fill_and_extend_from_within() {
+        let mut vec = chillvec![String::from("a"), String::from("b")];
+        assert!(vec.extend_from_within(..));
+        assert_eq!(vec, ["a", "b", "a", "b"]);
+        assert!(vec.extend_from_within(1..=1));
+        assert_eq!(vec, ["a", "b", "a", "b", "b"]);
+        assert!(vec.extend_from_within(5..));
+        assert_eq!(vec.len(), 5);
+        assert!(!vec.extend_from_within(..6));
+        assert_eq!(vec.len(), 5);
+
+        vec.fill(String::from("z"));
+        assert_eq!(vec, ["z"; 5]);
+
+        let mut empty = ChillVec::<String>::new();
+        empty.fill(String::from("z"));
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn growth_strategy() {
+        use growth::Exact;
+
+        let mut exact = ChillVec::<u8, Exact>::with_growth();
+        for i in 0..10 {
+            exact.push(i);
+            assert_eq!(exact.capacity(), exact.len());
+        }
+
+        let mut doubling = ChillVec::<u8>::new();
+        doubling.push(0);
+        assert_eq!(doubling.capacity(), 4);
+        doubling.extend_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(doubling.capacity(), 8);
+
+        let exact = doubling.into_growth::<Exact>();
+        assert_eq!(exact, [0, 1, 2, 3, 4]);
+        assert_eq!(exact.capacity(), 8);
+    }
+
+    #[test]
+    fn heap_size() {
+        let mut vec = ChillVec::<u64>::new();
+        assert_eq!(vec.heap_size(), 0);
+        vec.reserve_exact(3);
+        vec.push(1);
+        assert_eq!(vec.heap_size(), 24);
+        vec.shrink_to_fit();
+        assert_eq!(vec.heap_size(), 8);
+    }
+
+    #[test]
+    fn from_elem_and_zeroed() {
+        let vec = ChillVec::from_elem(String::from("a"), 3);
+        assert_eq!(vec, ["a", "a", "a"]);
+        assert_eq!(vec.capacity(), 3);
+        assert!(ChillVec::from_elem(1u8, 0).is_empty());
+
+        let zeroes = unsafe { ChillVec::<u64>::zeroed(1000) };
+        assert_eq!(zeroes.len(), 1000);
+        assert_eq!(zeroes.capacity(), 1000);
+        assert!(zeroes.iter().all(|&x| x == 0));
+
+        let empty = unsafe { ChillVec::<f32>::zeroed(0) };
+        assert!(empty.is_empty());
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    // Only compiles if drop check knows dropping a ChillVec can't look at the dangling reference
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn may_dangle() {
+        let mut vec = ChillVec::new();
+        let x = 1;
+        vec.push(&x);
+        let mut iter;
+        let y = 2;
+        iter = ChillVec::from([&y]).into_iter();
+        assert_eq!(iter.next(), Some(&2));
+    }
+
+    #[test]
+    fn extend() {
+        // An iterator whose size_hint undercounts what it actually yields
+        struct Liar(u32);
+        impl Iterator for Liar {
+            type Item = u32;
+            fn next(&mut self) -> Option<u32> {
+                self.0 = self.0.checked_sub(1)?;
+                Some(self.0)
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (1, None)
+            }
+        }
+
+        let mut vec = ChillVec::new();
+        vec.extend(0..3);
+        vec.extend(&[3, 4]);
+        vec.extend([4, 5].iter().map(|x| x + 1));
+        vec.extend(Liar(3));
+        vec.extend(core::iter::empty::<u32>());
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 2, 1, 0]);
+
+        let words: ChillVec<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(words, ["a", "b"]);
+        let collected: ChillVec<u32> = Liar(5).collect();
+        assert_eq!(collected, [4, 3, 2, 1, 0]);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes = [1, 7, 1, 8, 0];
+        let mut u = Unstructured::new(&bytes);
+        // Each element is preceded by a byte saying whether there is one
+        let vec = ChillVec::<u8>::arbitrary(&mut u).unwrap();
+        assert_eq!(vec, [7, 8]);
+
+        // Decodes the same way as std's Vec
+        let vec = ChillVec::<u8>::arbitrary_take_rest(Unstructured::new(&[1, 2, 3])).unwrap();
+        let std_vec = Vec::<u8>::arbitrary_take_rest(Unstructured::new(&[1, 2, 3])).unwrap();
+        assert_eq!(vec, std_vec[..]);
+        assert_eq!(vec, [2, 0]);
+
+        let mut u = Unstructured::new(&[]);
+        assert!(ChillVec::<u32>::arbitrary(&mut u).unwrap().is_empty());
+    }
+
+    #[test]
+    fn extract_if() {
+        let mut vec: ChillVec<String> = (0..10).map(|i| i.to_string()).collect();
+        let removed: ChillVec<String> = vec
+            .extract_if(|s| s.parse::<u32>().unwrap() % 3 == 0)
+            .collect();
+        assert_eq!(removed, ["0", "3", "6", "9"]);
+        assert_eq!(vec, ["1", "2", "4", "5", "7", "8"]);
+
+        // Dropping the iterator early keeps whatever it didn't look at
+        let mut vec = ChillVec::from([1, 2, 3, 4, 5, 6]);
+        let mut iter = vec.extract_if(|x| *x % 2 == 0);
+        assert_eq!(iter.next(), Some(2));
+        drop(iter);
+        assert_eq!(vec, [1, 3, 4, 5, 6]);
+
+        let mut vec = ChillVec::from([1, 2, 3]);
+        assert_eq!(vec.extract_if(|_| false).count(), 0);
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(vec.extract_if(|_| true).count(), 3);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn select() {
+        let vec: ChillVec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let selected = vec.select(&[2, 1, 2]).unwrap();
+        assert_eq!(selected, ["c", "b", "c"]);
+        assert_eq!(selected.capacity(), 3);
+
+        assert!(vec.select(&[]).unwrap().is_empty());
+        assert_eq!(vec.select(&[0, 3]), None);
+        assert_eq!(vec.select(&[usize::MAX]), None);
+    }
 }